@@ -1,4 +1,10 @@
+use std::marker::PhantomData;
+
 use super::world::World;
+use super::comp::Component;
+use super::resource::Resource;
+use super::entity::{Bundle, Token};
+use super::storage::Storage;
 
 /// # Command trait
 /// Defines a command that does an operation on the whole World
@@ -32,4 +38,172 @@ impl<T: Command> CommandWrapper for T{
     fn execute(&mut self, world: &mut World) {
         Command::execute(self, world);
     }
-}
\ No newline at end of file
+}
+
+/// How many Ticks the result of a Ticketed Command is kept for before it's dropped
+pub const COMMAND_RESULT_TICKS: u64 = 60;
+
+/// # Ticketed Command trait
+/// Defines a Command that produces a result for the System that sent it
+/// 
+/// Send it via `CommandWriter::send_with_ticket`, the result is then kept in the World  
+/// until it's taken out with `CommandResults` or `World::take_command_result`.  
+/// Results that aren't taken within `COMMAND_RESULT_TICKS` Ticks are dropped
+pub trait TicketedCommand: 'static{
+    type Output: 'static;
+    /// Execute the Command on specified World and produce the result
+    fn execute(&mut self, world: &mut World) -> Self::Output;
+}
+
+/// # Command Ticket
+/// Claims the result of a Ticketed Command
+/// 
+/// The Ticket knows the type of the result, so it can only be redeemed for the right one
+pub struct Ticket<T>{
+    id: u64,
+    _phantom: PhantomData<fn() -> T>
+}
+impl<T> Ticket<T>{
+    pub(crate) fn new(id: u64) -> Self{
+        Self{
+            id,
+            _phantom: PhantomData
+        }
+    }
+    /// Get the raw ID of this Ticket
+    pub fn id(&self) -> u64{
+        self.id
+    }
+}
+impl<T> Clone for Ticket<T>{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Ticket<T>{}
+
+/// Runs a Ticketed Command and stores it's result in the World under the Ticket
+pub(crate) struct Ticketed<C: TicketedCommand>{
+    pub(crate) ticket: u64,
+    pub(crate) command: C
+}
+impl<C: TicketedCommand> Command for Ticketed<C>{
+    const ID: &'static str = "_Ticketed";
+
+    fn execute(&mut self, world: &mut World) {
+        let result = self.command.execute(world);
+        world.store_command_result(self.ticket, result);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Built-in Commands
+///////////////////////////////////////////////////////////////////////////////
+
+/// # Spawn Command
+/// Spawns a new Entity with the given Bundle of Components
+pub struct Spawn<B: Bundle>(pub Option<B>);
+impl<B: Bundle + 'static> Command for Spawn<B>{
+    const ID: &'static str = "_Spawn";
+
+    fn execute(&mut self, world: &mut World) {
+        if let Some(bundle) = self.0.take(){
+            world.spawn().with_bundle(bundle).finish();
+        }
+    }
+}
+impl<B: Bundle + 'static> TicketedCommand for Spawn<B>{
+    type Output = usize;
+
+    fn execute(&mut self, world: &mut World) -> usize {
+        let Some(bundle) = self.0.take() else{
+            panic!("ERROR: Spawn Command was executed twice")
+        };
+        world.spawn().with_bundle(bundle).finish().id()
+    }
+}
+
+/// # Insert Command
+/// Inserts a Component to an existing Entity
+/// 
+/// Does nothing if the Entity is gone by the time the Command is executed,  
+/// even if another Entity took it's ID in the meantime
+pub struct Insert<C: Component>{
+    pub entity: Token,
+    pub comp: Option<C>
+}
+impl<C: Component> Command for Insert<C>{
+    const ID: &'static str = "_Insert";
+
+    fn execute(&mut self, world: &mut World) {
+        if !world.is_alive(&self.entity){
+            return
+        }
+        if let Some(comp) = self.comp.take(){
+            world.fetch_mut::<C>().insert(self.entity.id(), comp);
+        }
+    }
+}
+
+/// # Despawn Command
+/// Despawns the given Entity, does nothing if it's already gone
+/// 
+/// The Token is checked when the Command is executed, so an Entity that took a stale Token's ID is left alone
+pub struct Despawn(pub Token);
+impl Command for Despawn{
+    const ID: &'static str = "_Despawn";
+
+    fn execute(&mut self, world: &mut World) {
+        world.despawn_with_token(self.0);
+    }
+}
+
+/// # Register Component Command
+/// Registers the `C` Component in the World, letting Systems register Components at runtime
+/// 
+/// Does nothing if the Component is already registered
+pub struct RegisterComponent<C: Component>(PhantomData<C>);
+impl<C: Component> RegisterComponent<C>{
+    pub fn new() -> Self{
+        Self(PhantomData)
+    }
+}
+impl<C: Component> Default for RegisterComponent<C>{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<C: Component> Command for RegisterComponent<C>{
+    const ID: &'static str = "_RegisterComponent";
+
+    fn execute(&mut self, world: &mut World) {
+        if !world.has_comp(C::ID){
+            world.register_comp::<C>();
+        }
+    }
+}
+
+/// # Register Resource Command
+/// Registers the `R` Resource in the World, letting Systems register Resources at runtime
+/// 
+/// Does nothing if the Resource is already registered
+pub struct RegisterResource<R: Resource>(PhantomData<R>);
+impl<R: Resource> RegisterResource<R>{
+    pub fn new() -> Self{
+        Self(PhantomData)
+    }
+}
+impl<R: Resource> Default for RegisterResource<R>{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<R: Resource> Command for RegisterResource<R>{
+    const ID: &'static str = "_RegisterResource";
+
+    fn execute(&mut self, world: &mut World) {
+        if !world.has_res(R::ID){
+            world.register_res::<R>();
+        }
+    }
+}
@@ -1,15 +1,79 @@
 use super::storage::Storage;
+use super::world::World;
 
 /// # Component trait
 /// A trait identifying Components within the engine
 /// 
-/// `Storage` is anything implementing `Storage` trait
+/// `STORAGE` is anything implementing `Storage` trait, use `DefaultStorage<Self>` if unsure
 /// 
 /// `ID` is what the Component will be identified by in the World
 /// 
+/// `on_remove` is a hook ran when the Component's Entity gets despawned, before the Component is dropped.  
+/// By then every Component of the despawned Entities is already out of its Storage, 
+/// so the hook can fetch any Storage, including its own, but won't find the Entity's other Components there
+/// 
 /// ## WARNING
 /// Make sure your Component ID does not collide with other IDs from other plugins
 pub trait Component: Sized + 'static{
     type STORAGE: Storage<Self>;
     const ID: &'static str;
-}
\ No newline at end of file
+
+    /// Called when the Entity with this Component is despawned
+    fn on_remove(&mut self, _id: usize, _world: &World){}
+}
+
+/// # Snapshotable Component trait
+/// Marks Components that can be captured by `World::snapshot`
+/// 
+/// Requires both the Component and it's `STORAGE` to be `Clone`,  
+/// it is implemented automatically for every such Component
+/// 
+/// Note: The Component still has to be marked via `World::register_snapshot` to be captured
+pub trait SnapshotableComponent: Component<STORAGE: Clone> + Clone{}
+impl<C: Component<STORAGE: Clone> + Clone> SnapshotableComponent for C{}
+
+/// # Serializable Component trait
+/// A Component that can be turned into bytes and back, for `World::serialize_scene`
+/// 
+/// The byte format is entirely up to the Component, `deserialize` returns None if the bytes are not valid
+/// 
+/// Note: The Component still has to be marked via `World::register_serializable` to be saved
+pub trait SerializableComponent: Component{
+    /// Turn the Component into bytes
+    fn serialize(&self) -> Vec<u8>;
+    /// Rebuild the Component from bytes made by `serialize`
+    fn deserialize(bytes: &[u8]) -> Option<Self>;
+}
+
+/// # Component Set trait
+/// A set of Components that get registered in the World together
+/// 
+/// It is implemented by default on Tuples of Components up to 12 elements,  
+/// pairs well with `Bundle` for spawning Entities with the same set
+pub trait ComponentSet{
+    /// Register every Component of this set in the World
+    fn register(world: &mut World);
+}
+
+macro_rules! comp_set_impl {
+    ($($x:tt), *) => {
+        impl<$($x: Component), *> ComponentSet for ($($x,)*){
+            fn register(world: &mut World){
+                $(world.register_comp::<$x>();)*
+            }
+        }
+    }
+}
+
+comp_set_impl!(A);
+comp_set_impl!(A, B);
+comp_set_impl!(A, B, C);
+comp_set_impl!(A, B, C, D);
+comp_set_impl!(A, B, C, D, E);
+comp_set_impl!(A, B, C, D, E, F);
+comp_set_impl!(A, B, C, D, E, F, G);
+comp_set_impl!(A, B, C, D, E, F, G, H);
+comp_set_impl!(A, B, C, D, E, F, G, H, I);
+comp_set_impl!(A, B, C, D, E, F, G, H, I, J);
+comp_set_impl!(A, B, C, D, E, F, G, H, I, J, K);
+comp_set_impl!(A, B, C, D, E, F, G, H, I, J, K, L);
\ No newline at end of file
@@ -1,38 +1,55 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::time::{Duration, Instant};
 
 use super::system::*;
 use super::world::World;
-use super::resource::DeltaT;
+use super::comp::Component;
+use super::resource::{DeltaT, LogicPause, Resource};
+use super::events::Event;
+use super::fetch::{Access, RequestData};
 
 const MAX_SYS_PER_STAGE: usize = 5;
 const TICKS_PER_SECOND: u64 = 20; // Default: 20, subject to change
-// Additional value to push the Tickrate that's *just* short of next loop
-const TICKRATE_EPS: Duration = Duration::from_millis(5);
+// How many Logic Ticks can run in a single frame before we give up catching up
+const MAX_TICKS_PER_FRAME: u32 = 5;
+// How many rounds of Immediate Triggers can chain before the rest is left for the next Tick
+const MAX_TRIGGER_CHAIN: u32 = 16;
 
 // DO NOT TOUCH
 const TICKRATE: Duration = Duration::from_millis(1000/TICKS_PER_SECOND);
 
 type Stage = Vec<Box<dyn SystemWrapper>>;
+type Hook = Box<dyn FnMut(&mut World)>;
+type QueuedSystem = fn(&mut Dispatcher) -> Result<(), DispatchError>;
 
 /// # System Dispatcher
 /// Handles the execution of the Systems within the app
 /// 
 /// Has 2 loops:
 /// - Staller Loop -- Runs every frame
-/// - Logic Loop - Runs inside Staller Loop at most N times per second, specified by the Tickrate
+/// - Logic Loop - Runs inside Staller Loop at a fixed timestep, N times per second, specified by the Tickrate
+/// 
+/// Logic Systems always see the fixed Tickrate as their Logic Delta,
+/// Preprocessors and Postprocessors see the real Frame Delta
 /// 
 /// TODO: Make Tickrate adjustable at runtime
 pub struct Dispatcher{
-    _registry: HashMap<&'static str, SystemInfo>,
+    registry: HashMap<&'static str, SystemInfo>,
     preproc: Vec<Stage>,
     singlefires: HashMap<&'static str, Box<dyn SystemWrapper>>,
     logic: Vec<Stage>,
     event_responders: HashMap<&'static str, Vec<Box<dyn SystemWrapper>>>,
-    postproc: Vec<Stage>
+    postproc: Vec<Stage>,
+    frame_start_hooks: Vec<Hook>,
+    frame_end_hooks: Vec<Hook>,
+    tick_hooks: Vec<Hook>,
+    resilient: bool,
+    disabled: HashSet<&'static str>,
+    sets: HashMap<&'static str, Vec<&'static str>>
 }
 impl Dispatcher{
     /// Start building a new Dispatcher
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> DispatcherBuilder{
         DispatcherBuilder::new()
     }
@@ -40,145 +57,474 @@ impl Dispatcher{
     pub fn dispatch(&mut self, world: &mut World) -> Box<[i32]>{
         
         let mut last_frame = Instant::now();
-        let mut last_tick = Instant::now();
+        let mut accumulator = Duration::ZERO;
 
         loop{
             // Update Frame Delta
-            {
-                let elapsed = last_frame.elapsed();
-                world.fetch_res_mut::<DeltaT>().set_delta_frame( elapsed.as_micros(), elapsed.as_secs_f32());
+            let elapsed = {
+                let now = Instant::now();
+                let elapsed = now - last_frame;
+                last_frame = now;
+                elapsed
+            };
+
+            if let Some(error_codes) = self.frame(world, &mut accumulator, elapsed){
+                return error_codes
             }
+        }
+    }
+    /// Set whether the Dispatcher should survive panicking Systems
+    /// 
+    /// In resilient mode, a System that panics is logged and disabled, and the loop continues.  
+    /// Caveats of `catch_unwind` apply: panics that abort aren't caught, 
+    /// and the World may be left half-way through the System's changes
+    pub fn set_resilient(&mut self, resilient: bool){
+        self.resilient = resilient;
+    }
+    /// Get the IDs of Systems that have been disabled after panicking
+    pub fn disabled_systems(&self) -> &HashSet<&'static str>{
+        &self.disabled
+    }
+    /// Add a hook that runs at the start of every frame, before the Preprocessors
+    /// 
+    /// Hooks are lighter than Systems, meant for glue like profiling and frame pacing
+    pub fn on_frame_start(&mut self, hook: impl FnMut(&mut World) + 'static){
+        self.frame_start_hooks.push(Box::new(hook));
+    }
+    /// Add a hook that runs at the end of every frame, after the Postprocessors
+    pub fn on_frame_end(&mut self, hook: impl FnMut(&mut World) + 'static){
+        self.frame_end_hooks.push(Box::new(hook));
+    }
+    /// Add a hook that runs at the end of every Logic Tick
+    pub fn on_tick(&mut self, hook: impl FnMut(&mut World) + 'static){
+        self.tick_hooks.push(Box::new(hook));
+    }
+    /// Run a single frame of the Staller Loop
+    /// 
+    /// Returns the error codes if the app has been requested to shut down
+    fn frame(&mut self, world: &mut World, accumulator: &mut Duration, elapsed: Duration) -> Option<Box<[i32]>>{
+        world.fetch_res_mut::<DeltaT>().set_delta_frame(elapsed.as_micros(), elapsed.as_secs_f32());
+
+        for hook in self.frame_start_hooks.iter_mut(){
+            hook(world);
+        }
 
-            // -- PREPROCESSORS --
-            for stage in self.preproc.iter_mut(){
-                for system in stage.iter_mut(){
-                    system.execute(world);
-                }
+        // -- PREPROCESSORS --
+        for stage in self.preproc.iter_mut(){
+            for system in stage.iter_mut(){
+                run_system(system.as_mut(), world, self.resilient, &mut self.disabled);
             }
+        }
 
-            // -- LOGIC LOOP --
-            if last_tick.elapsed() >= TICKRATE + TICKRATE_EPS{
-                // Update Logic Delta
-                {
-                    let elapsed = last_tick.elapsed();
-                    world.fetch_res_mut::<DeltaT>().set_delta_logic(elapsed.as_micros(), elapsed.as_secs_f32());
-                }
+        // -- LOGIC LOOP --
+        // Paused time doesn't accumulate, we don't want a burst of Ticks on resume
+        let paused = world.fetch_res::<LogicPause>().is_paused();
+        if !paused{
+            self.step_logic(world, accumulator, elapsed);
+        }
 
-                // -- Logic Systems --
-                for stage in self.logic.iter_mut(){
-                    for system in stage.iter_mut(){
-                        system.execute(world);
-                    }
-                }
-                // -- Singlefires --
-                for trigger in world.take_triggers(){
-                    // We have triggers for systems, but may not have any systems *to* trigger
-                    if let Some(system) = self.singlefires.get_mut(trigger){
-                        system.execute(world);
-                    }
-                }
-                // -- Event Responders --
-                for event in world.get_events().get_active_events(){
-                    // We have events, but may not have any responders for them
-                    if let Some(responders) = self.event_responders.get_mut(event){
-                        for system in responders.iter_mut(){
-                            system.execute(world);
-                        }
+        // -- POSTPROCESSORS --
+        for stage in self.postproc.iter_mut(){
+            for system in stage.iter_mut(){
+                run_system(system.as_mut(), world, self.resilient, &mut self.disabled);
+            }
+        }
 
-                    }
-                }
-                // -- Commands --
-                for mut command in world.take_commands(){
-                    command.execute(world);
-                }
+        for hook in self.frame_end_hooks.iter_mut(){
+            hook(world);
+        }
 
-                // Update last Logic Tick
-                last_tick = Instant::now();
+        // Systems queued during the frame join in between frames
+        self.add_queued_systems(world);
+        
+        // Check system-level events
+        {
+            use super::events;
+            // Borrow for an extended period of time
+            let events = world.get_events();
+
+            // App exit
+            // Check both buffers, the buffers only swap on Logic Ticks
+            let event = events.get_writer::<events::ExitApp>();
+            if event.prev_event_count() + event.current_event_count() > 0{
+                let error_codes = event.prev_iter().chain(event.current_iter()).map(|event| event.0).collect::<Box<[i32]>>();
 
-                world.fetch_res_mut::<DeltaT>().incr_logic_frame();
+                eprintln!("{} requests for shutdown have been sent with following error codes: {:?}", error_codes.len(), error_codes);
+
+                return Some(error_codes)
             }
+        }
 
-            // -- POSTPROCESSORS --
-            for stage in self.postproc.iter_mut(){
-                for system in stage.iter_mut(){
-                    system.execute(world);
+        world.fetch_res_mut::<DeltaT>().incr_frame();
+        None
+    }
+    /// Advance the Logic Loop by the elapsed frame time
+    /// 
+    /// Runs as many fixed Ticks as have accumulated, 
+    /// dropping the backlog if it exceeds `MAX_TICKS_PER_FRAME`
+    fn step_logic(&mut self, world: &mut World, accumulator: &mut Duration, elapsed: Duration){
+        *accumulator += elapsed;
+
+        let mut ticks = 0;
+        while *accumulator >= TICKRATE{
+            if ticks == MAX_TICKS_PER_FRAME{
+                // We're too far behind, don't spiral trying to catch up
+                *accumulator = Duration::ZERO;
+                break
+            }
+            *accumulator -= TICKRATE;
+            self.logic_tick(world);
+            ticks += 1;
+        }
+    }
+    /// Run exactly one Logic Tick, no matter how much time has passed
+    /// 
+    /// Meant for deterministic simulations, like Lockstep networking:
+    /// - The Logic Delta is always the fixed Tickrate
+    /// - Stages, Singlefires and Event Responders always run in the same order
+    /// - Preprocessors, Postprocessors and frame Hooks are not ran
+    /// 
+    /// Pair it with the `Rng` Resource to keep randomness deterministic too
+    pub fn lockstep_tick(&mut self, world: &mut World){
+        self.logic_tick(world);
+        self.add_queued_systems(world);
+    }
+    /// Run a single Logic Tick
+    fn logic_tick(&mut self, world: &mut World){
+        // Logic always advances by the fixed Tickrate
+        world.fetch_res_mut::<DeltaT>().set_delta_logic(TICKRATE.as_micros(), TICKRATE.as_secs_f32());
+
+        // -- Logic Systems --
+        let tick = world.current_tick();
+        for stage in self.logic.iter_mut(){
+            for system in stage.iter_mut(){
+                // Throttled Systems sit the other Ticks out
+                if !tick.is_multiple_of(system.tick_interval()){
+                    continue
                 }
+                run_system(system.as_mut(), world, self.resilient, &mut self.disabled);
             }
-            
-            // Check system-level events
-            {
-                use super::events;
-                // Borrow for an extended period of time
-                let events = world.get_events();
+        }
+        // -- Singlefires --
+        for trigger in world.take_triggers(){
+            // We have triggers for systems, but may not have any systems *to* trigger
+            if let Some(system) = self.singlefires.get_mut(trigger){
+                run_system(system.as_mut(), world, self.resilient, &mut self.disabled);
+            }
+        }
+        self.run_immediate_triggers(world);
+        // -- Event Responders --
+        for event in world.get_events().get_active_events(){
+            // We have events, but may not have any responders for them
+            if let Some(responders) = self.event_responders.get_mut(event){
+                for system in responders.iter_mut(){
+                    run_system(system.as_mut(), world, self.resilient, &mut self.disabled);
+                }
+
+            }
+        }
+        self.run_immediate_triggers(world);
+        // -- Commands --
+        for mut command in world.take_commands(){
+            command.execute(world);
+        }
 
-                // App exit
-                let event = events.get_reader::<events::ExitApp>();
-                if event.event_count() > 0{
-                    let error_codes = event.iter().map(|event| event.0).collect::<Box<[i32]>>();
+        // Events sent this Tick are read on the next one
+        world.end_tick();
 
-                    eprintln!("{} requests for shutdown have been sent with following error codes: {:?}", event.event_count(), error_codes);
+        world.fetch_res_mut::<DeltaT>().incr_logic_frame();
 
-                    return error_codes
+        for hook in self.tick_hooks.iter_mut(){
+            hook(world);
+        }
+    }
+    /// Run Singlefires fired by Immediate Triggers until there are none left
+    /// 
+    /// Singlefires can fire more Immediate Triggers, those run in the next round.  
+    /// Gives up after `MAX_TRIGGER_CHAIN` rounds, so Singlefires triggering each other don't hang the Tick
+    fn run_immediate_triggers(&mut self, world: &mut World){
+        for _ in 0..MAX_TRIGGER_CHAIN{
+            let triggers = world.take_immediate_triggers();
+            if triggers.is_empty(){
+                return
+            }
+            for trigger in triggers{
+                if let Some(system) = self.singlefires.get_mut(trigger){
+                    run_system(system.as_mut(), world, self.resilient, &mut self.disabled);
                 }
             }
+        }
+        eprintln!("WARNING: Immediate Triggers kept firing for {} rounds, the rest will fire on the next Tick", MAX_TRIGGER_CHAIN);
+    }
+    /// Get the layout of the built Stages
+    /// 
+    /// Returns the IDs of Systems in each Stage, in order of execution, for every staged section
+    pub fn stage_layout(&self) -> Vec<(SystemSection, Vec<Vec<&'static str>>)>{
+        [
+            (SystemSection::Preprocessor, &self.preproc),
+            (SystemSection::Logic, &self.logic),
+            (SystemSection::Postprocessor, &self.postproc)
+        ].into_iter()
+            .map(|(section, stages)| 
+                (section, stages.iter()
+                                .map(|stage| stage.iter().map(|system| system.id()).collect())
+                                .collect())
+            ).collect()
+    }
+    /// Get the IDs of staged Systems in the exact order they execute in
+    /// 
+    /// Flattens `stage_layout`: Preprocessors, then Logic, then Postprocessors.  
+    /// Singlefires and Event Responders only run on demand, so they're not included
+    pub fn execution_order(&self) -> Vec<&'static str>{
+        self.stage_layout().into_iter()
+            .flat_map(|(_, stages)| stages.into_iter().flatten())
+            .collect()
+    }
+    /// Add a System to the already built Dispatcher
+    /// 
+    /// The System's section gets its Stages rebuilt, so it runs in the right order from the next frame on.  
+    /// Meant for loading plugins at runtime
+    /// 
+    /// Unlike the Builder, this doesn't check the World for the data the System requests,  
+    /// make sure it's registered beforehand. Overriding Systems this way is not supported
+    pub fn add_system_dynamic<S: System>(&mut self) -> Result<(), DispatchError>{
+        let info = SystemInfo::new::<S>();
+
+        if self.registry.contains_key(info.id){
+            return Err(DispatchError::ConflictingId(info.id))
+        }
+        if let Some(dependency) = info.depends.iter().find(|dep| !self.registry.contains_key(*dep)){
+            return Err(DispatchError::MissingDependency{ system: info.id, dependency })
+        }
+        if let Some((system, target)) = find_cross_type_run_order(&self.registry, &info){
+            return Err(DispatchError::CrossTypeRunOrder{ system: system.id, target: target.id })
+        }
+        if S::TICK_INTERVAL == 0{
+            return Err(DispatchError::ZeroTickInterval(info.id))
+        }
+
+        let system: Box<dyn SystemWrapper> = Box::new(S::new());
+        match info.sys_type{
+            SystemType::Preprocessor => restage(&mut self.preproc, system, &self.sets),
+            SystemType::Logic => restage(&mut self.logic, system, &self.sets),
+            SystemType::Singlefire => {
+                self.singlefires.insert(info.id, system);
+            },
+            SystemType::EventResponder(event_id) => {
+                self.event_responders.entry(event_id).or_default().push(system);
+            },
+            SystemType::Postprocessor => restage(&mut self.postproc, system, &self.sets),
+        }
+        self.registry.insert(info.id, info);
+
+        Ok(())
+    }
+    /// Add the Systems queued in the `SystemQueue` Resource
+    /// 
+    /// Systems that get rejected are logged and dropped
+    fn add_queued_systems(&mut self, world: &mut World){
+        let queued = std::mem::take(&mut world.fetch_res_mut::<SystemQueue>().queue);
+
+        for add in queued{
+            if let Err(error) = add(self){
+                eprintln!("ERROR: Queued System could not be added: {:?}", error);
+            }
+        }
+    }
+    /// Get the metadata of the System with the given ID
+    /// 
+    /// The counterpart to `stage_layout`, meant for tooling and debug overlays
+    pub fn system_info(&self, id: &str) -> Option<SystemInfoView<'_>>{
+        self.registry.get(id).map(|info| SystemInfoView{
+            id: info.id,
+            depends: info.depends,
+            run_ord: info.run_ord,
+            sys_type: &info.sys_type
+        })
+    }
+}
+
+/// Run a single System, skipping it if it's disabled
+/// 
+/// If the Dispatcher is resilient, the System's panic is caught and the System gets disabled
+fn run_system(system: &mut dyn SystemWrapper, world: &mut World, resilient: bool, disabled: &mut HashSet<&'static str>){
+    if disabled.contains(system.id()){
+        return
+    }
+    if !resilient{
+        system.execute(world);
+        return
+    }
 
-            // Clear Events
-            world.swap_event_buffers();
+    let result = std::panic::catch_unwind(
+        std::panic::AssertUnwindSafe(|| system.execute(world)));
 
-            // Update last Frame Tick
-            last_frame = Instant::now();
+    if result.is_err(){
+        eprintln!("ERROR: System {} has panicked and has been disabled", system.id());
+        disabled.insert(system.id());
+    }
+}
 
-            world.fetch_res_mut::<DeltaT>().incr_frame();
+/// Find a Run Order between the new System and a registered System of a different type
+/// 
+/// Returns the System that has the Run Order and the System it targets
+fn find_cross_type_run_order<'a>(registry: &'a HashMap<&'static str, SystemInfo>, info: &'a SystemInfo) -> Option<(&'a SystemInfo, &'a SystemInfo)>{
+    let targets = |run_ord: &'static [RunOrder]| run_ord.iter().filter_map(|order| match order{
+        RunOrder::Before(id) | RunOrder::After(id) => Some(*id),
+        RunOrder::BeforeSet(_) | RunOrder::AfterSet(_) => None,
+    });
+
+    for other in registry.values(){
+        // It's being overriden, so it's going away anyway
+        if other.id == info.id || other.sys_type == info.sys_type{
+            continue
+        }
+        if targets(info.run_ord).any(|id| id == other.id){
+            return Some((info, other))
         }
+        if targets(other.run_ord).any(|id| id == info.id){
+            return Some((other, info))
+        }
+    }
+    None
+}
+
+/// Rebuild already built Stages with an extra System in them
+fn restage(stages: &mut Vec<Stage>, system: Box<dyn SystemWrapper>, sets: &HashMap<&'static str, Vec<&'static str>>){
+    let mut builder = StagesBuilder::new();
+    for system in stages.drain(..).flatten(){
+        builder.add(system);
     }
+    builder.add(system);
+    *stages = builder.build(sets);
 }
 
 /// # Dispatcher Builder
 /// Handles the building of the Dispatcher without letting anything disrupt
 /// 
-/// Make sure to use `.build()` once you're done
+/// Components, Resources and Events can be registered alongside the Systems using them,  
+/// they get registered in the World once the Dispatcher is built
+/// 
+/// Make sure to use `.build(world)` once you're done
 #[must_use]
 pub struct DispatcherBuilder{
     registry: HashMap<&'static str, SystemInfo>,
+    registrations: Vec<fn(&mut World)>,
     preproc: StagesBuilder,
     logic: StagesBuilder,
     singlefires: HashMap<&'static str, Box<dyn SystemWrapper>>,
     event_responders: HashMap<&'static str, Vec<Box<dyn SystemWrapper>>>,
     postproc: StagesBuilder,
+    sets: HashMap<&'static str, Vec<&'static str>>
 }
 impl DispatcherBuilder{
     /// Start building a new Dispatcher
     pub fn new() -> Self{
         Self{
             registry: HashMap::new(),
+            registrations: Vec::new(),
             preproc: StagesBuilder::new(),
             logic: StagesBuilder::new(),
             singlefires: HashMap::new(),
             event_responders: HashMap::new(),
-            postproc: StagesBuilder::new(),            
+            postproc: StagesBuilder::new(),
+            sets: HashMap::new()
         }
     }
     /// Add a System to the Dispatcher
+    /// 
+    /// Panics if the System's Run Orders target a System of a different type, or the other way around
     pub fn add<S: System>(&mut self){
-        // The System has the same ID but is not an override, we can't have it here
-        if self.registry.contains_key(S::ID) && !S::OVERRIDE{
-            panic!("ERROR: Conflicting system IDs {}\nDid you mean to override the System?", S::ID)
+        self.add_wrapped(SystemInfo::new::<S>(), Box::new(S::new()));
+    }
+    /// Add an Exclusive System to the Dispatcher
+    pub fn add_exclusive<S: ExclusiveSystem>(&mut self){
+        self.add_wrapped(SystemInfo::new_exclusive::<S>(), Box::new(ExclusiveContainer(S::new())));
+    }
+    /// Put an already wrapped System where it belongs
+    /// 
+    /// Every System type goes through the same Registry, so IDs can't collide across types either
+    fn add_wrapped(&mut self, info: SystemInfo, system: Box<dyn SystemWrapper>){
+        if let Some(previous) = self.registry.get(info.id){
+            // The System has the same ID but is not an override, we can't have it here
+            if !info.overr{
+                panic!("ERROR: Conflicting system IDs {}\nDid you mean to override the System?", info.id)
+            }
+            // The override may be of a different type, so the previous System won't get replaced in place
+            self.remove_system(info.id, previous.sys_type);
         }
+        if system.tick_interval() == 0{
+            panic!("ERROR: System {} has a Tick Interval of 0", info.id)
+        }
+        self.verify_run_order_types(&info);
         // Also acts as an auto override for the registry, neat
-        self.registry.insert(S::ID, SystemInfo::new::<S>());
+        self.registry.insert(info.id, info);
 
-        match S::TYPE{
-            SystemType::Preprocessor => self.preproc.add::<S>(),
-            SystemType::Logic => self.logic.add::<S>(),
+        match system.sys_type(){
+            SystemType::Preprocessor => self.preproc.add(system),
+            SystemType::Logic => self.logic.add(system),
             SystemType::Singlefire => {
-                self.singlefires.insert(S::ID, Box::new(S::new()));
+                self.singlefires.insert(system.id(), system);
             },
             SystemType::EventResponder(event_id) => {
-                self.event_responders.entry(event_id).or_insert(Vec::new()).push(Box::new(S::new()));
+                self.event_responders.entry(event_id).or_default().push(system);
             }
-            SystemType::Postprocessor => self.postproc.add::<S>(),
+            SystemType::Postprocessor => self.postproc.add(system),
+        }
+    }
+    /// Take the System out of the section it was put in
+    fn remove_system(&mut self, id: &'static str, sys_type: SystemType){
+        match sys_type{
+            SystemType::Preprocessor => {
+                self.preproc.systems.remove(id);
+            },
+            SystemType::Logic => {
+                self.logic.systems.remove(id);
+            },
+            SystemType::Singlefire => {
+                self.singlefires.remove(id);
+            },
+            SystemType::EventResponder(event_id) => {
+                if let Some(responders) = self.event_responders.get_mut(event_id){
+                    responders.retain(|system| system.id() != id);
+                }
+            },
+            SystemType::Postprocessor => {
+                self.postproc.systems.remove(id);
+            },
+        }
+    }
+    /// Make sure the System's Run Orders, and the ones targeting it, stay within the same System Type
+    /// 
+    /// Systems of different types run in different parts of the loop, so ordering between them does nothing.  
+    /// Set Run Orders are exempt, that's how you order across types on purpose
+    fn verify_run_order_types(&self, info: &SystemInfo){
+        if let Some((system, target)) = find_cross_type_run_order(&self.registry, info){
+            panic!("ERROR: System {} ({:?}) has a Run Order against System {} ({:?})\nSystems can only be ordered against Systems of the same type, use a System Set to order across types", system.id, system.sys_type, target.id, target.sys_type)
+        }
+    }
+    /// Put the System with the given ID into a System Set
+    /// 
+    /// Other Systems can then order themselves against the whole Set  
+    /// via `RunOrder::BeforeSet` and `RunOrder::AfterSet`
+    pub fn add_to_set(&mut self, id: &'static str, set: &'static str){
+        let members = self.sets.entry(set).or_default();
+        if !members.contains(&id){
+            members.push(id);
         }
     }
+    /// Register `C` Component in the World once the Dispatcher is built
+    pub fn register_comp<C: Component>(&mut self){
+        self.registrations.push(|world| world.register_comp::<C>());
+    }
+    /// Register `R` Resource in the World once the Dispatcher is built
+    pub fn register_res<R: Resource>(&mut self){
+        self.registrations.push(|world| world.register_res::<R>());
+    }
+    /// Register `E` Event in the World once the Dispatcher is built
+    pub fn register_event<E: Event>(&mut self){
+        self.registrations.push(|world| world.register_event::<E>());
+    }
     /// Verify dependencies of each System
     fn verify_deps(&self){
         for system in self.registry.values(){
@@ -189,22 +535,247 @@ impl DispatcherBuilder{
             }
         }
     }
+    /// Verify that everything each System requests is registered in the World
+    fn verify_access(&self, world: &World){
+        for system in self.registry.values(){
+            for access in system.access.iter(){
+                let (kind, id, registered) = match *access{
+                    Access::Comp(id) | Access::CompMut(id) => ("Component", id, world.has_comp(id)),
+                    Access::Res(id) | Access::ResMut(id) => ("Resource", id, world.has_res(id)),
+                    Access::EventRead(id) | Access::EventWrite(id) | Access::EventConsume(id) => ("Event", id, world.has_event(id)),
+                    Access::Commands | Access::Triggers => continue,
+                };
+                if !registered{
+                    panic!("ERROR: System {} requests {} {}, which is not registered in the World", system.id, kind, id)
+                }
+            }
+        }
+    }
+    /// Register the Resources the Dispatcher itself relies on, if they're missing
+    /// 
+    /// Returns the IDs of the Resources that had to be registered
+    fn ensure_engine_resources(world: &mut World) -> Vec<&'static str>{
+        let mut registered = Vec::new();
+
+        if !world.has_res(DeltaT::ID){
+            world.register_res::<DeltaT>();
+            registered.push(DeltaT::ID);
+        }
+        if !world.has_res(LogicPause::ID){
+            world.register_res::<LogicPause>();
+            registered.push(LogicPause::ID);
+        }
+        if !world.has_res(SystemQueue::ID){
+            world.register_res::<SystemQueue>();
+            registered.push(SystemQueue::ID);
+        }
+
+        for id in registered.iter(){
+            eprintln!("WARNING: Engine Resource {} was not registered, registered it automatically", id);
+        }
+
+        registered
+    }
+    /// Find Run Orders targeting Systems or Sets that were never added
+    fn check_run_orders(&self, report: &mut BuildReport){
+        for system in self.registry.values(){
+            for order in system.run_ord.iter(){
+                let exists = match order{
+                    RunOrder::Before(id) | RunOrder::After(id) => self.registry.contains_key(id),
+                    RunOrder::BeforeSet(set) | RunOrder::AfterSet(set) => self.sets.contains_key(set),
+                };
+                if !exists{
+                    report.warnings.push(BuildWarning::UnknownRunOrder{ system: system.id, target: order.value() });
+                }
+            }
+        }
+    }
+    /// Find staged Systems that neither have Run Orders relating them to anything, nor share any data with other Systems
+    /// 
+    /// Systems that request no data of their own, and engine Resources, are left out
+    fn check_isolated(&self, report: &mut BuildReport){
+        let data = |info: &SystemInfo| info.access.iter().filter_map(|access| match *access{
+            Access::Comp(id) | Access::CompMut(id) => Some(("Component", id)),
+            // The Dispatcher itself touches engine Resources, they don't count
+            Access::Res(id) | Access::ResMut(id) if [DeltaT::ID, LogicPause::ID, SystemQueue::ID].contains(&id) => None,
+            Access::Res(id) | Access::ResMut(id) => Some(("Resource", id)),
+            Access::EventRead(id) | Access::EventWrite(id) | Access::EventConsume(id) => Some(("Event", id)),
+            Access::Commands | Access::Triggers => None,
+        }).collect::<HashSet<_>>();
+
+        let targeted = |system: &'static str| self.registry.values().any(|other| other.run_ord.iter().any(|order| match order{
+            RunOrder::Before(id) | RunOrder::After(id) => *id == system,
+            RunOrder::BeforeSet(set) | RunOrder::AfterSet(set) => self.sets.get(set).is_some_and(|members| members.contains(&system)),
+        }));
+
+        for system in self.registry.values(){
+            if !matches!(system.sys_type, SystemType::Preprocessor | SystemType::Logic | SystemType::Postprocessor){
+                continue
+            }
+            let own = data(system);
+            if own.is_empty() || !system.run_ord.is_empty() || targeted(system.id){
+                continue
+            }
+            let shared = self.registry.values()
+                .filter(|other| other.id != system.id)
+                .any(|other| !own.is_disjoint(&data(other)));
+            if !shared{
+                report.warnings.push(BuildWarning::IsolatedSystem(system.id));
+            }
+        }
+    }
+    /// Find Event Responders listening to Events that are not registered in the World
+    fn check_responders(&self, world: &World, report: &mut BuildReport){
+        for (event, responders) in self.event_responders.iter(){
+            if world.has_event(event){
+                continue
+            }
+            for responder in responders.iter(){
+                report.warnings.push(BuildWarning::UnregisteredEvent{ system: responder.id(), event });
+            }
+        }
+    }
     /// Build the Dispatcher
-    pub fn build(self) -> Dispatcher{
+    /// 
+    /// Registers the pending Components, Resources and Events in the World,  
+    /// then checks that everything the Systems request is registered
+    /// 
+    /// Engine Resources such as `DeltaT` and `LogicPause` are registered automatically if they're missing
+    pub fn build(self, world: &mut World) -> Dispatcher{
+        self.build_with_report(world).0
+    }
+    /// Build the Dispatcher and report anything suspicious about the resulting layout
+    /// 
+    /// The warnings don't stop the build, they're there to catch mistakes like typos in Run Orders
+    pub fn build_with_report(self, world: &mut World) -> (Dispatcher, BuildReport){
+
+        for register in self.registrations.iter(){
+            register(world);
+        }
+        let auto_registered = Self::ensure_engine_resources(world);
 
         self.verify_deps();
+        self.verify_access(world);
 
-        Dispatcher{
-            _registry: self.registry,
-            preproc: self.preproc.build(),
+        let mut report = BuildReport{ warnings: Vec::new(), parallelism: Vec::new() };
+        for id in auto_registered{
+            report.warnings.push(BuildWarning::AutoRegistered(id));
+        }
+        self.check_run_orders(&mut report);
+        self.check_responders(world, &mut report);
+        self.check_isolated(&mut report);
+        // The Registry is a HashMap, keep the report the same from build to build
+        report.warnings.sort();
+
+        let dispatcher = Dispatcher{
+            registry: self.registry,
+            preproc: self.preproc.build(&self.sets),
             singlefires: self.singlefires,
-            logic: self.logic.build(),
+            logic: self.logic.build(&self.sets),
             event_responders: self.event_responders,
-            postproc: self.postproc.build(),
+            postproc: self.postproc.build(&self.sets),
+            frame_start_hooks: Vec::new(),
+            frame_end_hooks: Vec::new(),
+            tick_hooks: Vec::new(),
+            resilient: false,
+            disabled: HashSet::new(),
+            sets: self.sets
+        };
+
+        for (section, stages) in dispatcher.stage_layout(){
+            report.parallelism.push((section, stages.iter().map(|stage| stage.len()).collect()));
+        }
+
+        (dispatcher, report)
+    }
+}
+
+impl Default for DispatcherBuilder{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// # Build Report
+/// Warnings collected while building the Dispatcher
+/// 
+/// Also holds the number of Systems in each Stage of every staged section, in order of execution.  
+/// Lots of narrow Stages mean the Run Orders are serializing the section, 
+/// Stages stuck at `MAX_SYS_PER_STAGE` mean there's room to go wider
+#[derive(Debug, Default)]
+pub struct BuildReport{
+    pub warnings: Vec<BuildWarning>,
+    pub parallelism: Vec<(SystemSection, Vec<usize>)>
+}
+impl BuildReport{
+    /// Check if the build went without any warnings
+    pub fn is_clean(&self) -> bool{
+        self.warnings.is_empty()
+    }
+}
+
+/// # Build Warning
+/// Something that doesn't break the Dispatcher, but likely isn't what was intended
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BuildWarning{
+    /// The System has a Run Order against a System that does not exist, the Run Order is ignored
+    UnknownRunOrder{ system: &'static str, target: &'static str },
+    /// The Event Responder listens to an Event that is not registered, it will never run
+    UnregisteredEvent{ system: &'static str, event: &'static str },
+    /// The engine Resource was missing from the World and got registered by the build
+    AutoRegistered(&'static str),
+    /// The System has no Run Orders relating it to other Systems and shares no data with them,  
+    /// it likely reads something nobody writes or the other way around
+    IsolatedSystem(&'static str)
+}
+
+/// # System Queue Resource
+/// Lets Systems add other Systems to the Dispatcher while it's running
+/// 
+/// The Dispatcher can't be reached from within a System, so they're queued here instead  
+/// and added with `Dispatcher::add_system_dynamic` once the current frame is over.  
+/// The same caveats apply, the World data they request has to be registered already
+pub struct SystemQueue{
+    queue: Vec<QueuedSystem>
+}
+impl SystemQueue{
+    /// Queue a System to be added after the current frame
+    pub fn add<S: System>(&mut self){
+        self.queue.push(Dispatcher::add_system_dynamic::<S>);
+    }
+    /// Get the number of Systems waiting to be added
+    pub fn len(&self) -> usize{
+        self.queue.len()
+    }
+    /// Check if there are no Systems waiting to be added
+    pub fn is_empty(&self) -> bool{
+        self.queue.is_empty()
+    }
+}
+impl Resource for SystemQueue{
+    const ID: &'static str = "SystemQueue";
+
+    fn new() -> Self {
+        Self{
+            queue: Vec::new()
         }
     }
 }
 
+/// # Dispatch Error
+/// Why a System couldn't be added to an already built Dispatcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DispatchError{
+    /// A System with the same ID is already in the Dispatcher
+    ConflictingId(&'static str),
+    /// The System depends on a System that isn't in the Dispatcher
+    MissingDependency{ system: &'static str, dependency: &'static str },
+    /// The System has a Run Order against a System of a different type, or the other way around
+    CrossTypeRunOrder{ system: &'static str, target: &'static str },
+    /// The System has a Tick Interval of 0
+    ZeroTickInterval(&'static str)
+}
+
 /// # System Information
 /// A collection of data for the Dispatcher's Registry to keep track of
 /// 
@@ -214,7 +785,8 @@ pub struct SystemInfo{
     pub depends: &'static [&'static str],
     pub run_ord: &'static [RunOrder],
     pub sys_type: SystemType,
-    pub overr: bool
+    pub overr: bool,
+    pub access: Vec<Access>
 }
 impl SystemInfo{
     fn new<S: System>() -> Self{
@@ -223,31 +795,56 @@ impl SystemInfo{
             depends: S::DEPENDS,
             run_ord: S::RUNORD,
             sys_type: S::TYPE,
-            overr: S::OVERRIDE
+            overr: S::OVERRIDE,
+            access: S::Data::<'static>::describe()
         }
     }
+    fn new_exclusive<S: ExclusiveSystem>() -> Self{
+        Self{
+            id: S::ID,
+            depends: S::DEPENDS,
+            run_ord: S::RUNORD,
+            sys_type: S::TYPE,
+            overr: S::OVERRIDE,
+            // Exclusive Systems take the whole World, there's nothing to verify
+            access: Vec::new()
+        }
+    }
+}
+
+/// # System Information View
+/// A read-only look at a registered System's metadata, get it from `Dispatcher::system_info`
+pub struct SystemInfoView<'a>{
+    pub id: &'static str,
+    pub depends: &'static [&'static str],
+    pub run_ord: &'static [RunOrder],
+    pub sys_type: &'a SystemType
 }
 
 /// # Stages Builder
 /// Builds a stage graph for Dispatcher to execute using provided Systems
+/// 
+/// Systems within a layer are kept sorted by ID, so the same Systems always build the same Stages
 #[must_use]
 struct StagesBuilder{
-    systems: HashMap<&'static str, Box<dyn SystemWrapper>>
+    systems: BTreeMap<&'static str, Box<dyn SystemWrapper>>
 }
 impl StagesBuilder{
     /// Start building a new collection of Stages
     fn new() -> Self{
         Self{
-            systems: HashMap::new()
+            systems: BTreeMap::new()
         }
     }
     /// Add a System to this builder
-    fn add<S: System>(&mut self){
+    fn add(&mut self, system: Box<dyn SystemWrapper>){
         // Realized there's no need to put overrides into a sepparate queue, we haven't started the build process yet, so we can safely override them right here
-        self.systems.insert(S::ID, Box::new(S::new()));
+        self.systems.insert(system.id(), system);
     }
     /// Build the graph
-    fn build_run_order_graph(&self) -> Vec<Vec<&'static str>>{
+    /// 
+    /// `sets` are the System Sets, which Set Run Orders get expanded to
+    fn build_run_order_graph(&self, sets: &HashMap<&'static str, Vec<&'static str>>) -> Vec<Vec<&'static str>>{
         // Welcome to indentation hell
         // Population: Graph Building
 
@@ -256,7 +853,7 @@ impl StagesBuilder{
 
         // Prepare the graph
         // Yeah, it's kinda a mess
-        let mut graph: Vec<HashMap<&'static str, &'static [RunOrder]>> 
+        let mut graph: Vec<BTreeMap<&'static str, &'static [RunOrder]>> 
             = Vec::from([
                     self.systems.values()
                                 .map(|system|
@@ -290,6 +887,22 @@ impl StagesBuilder{
                                 shifts.insert(*system_id);
                             }
                         },
+                        // Same as above, but for every member of the Set
+                        // A System can be in the Set it orders against, so skip itself
+                        RunOrder::BeforeSet(set) => {
+                            for member in sets.get(set).into_iter().flatten(){
+                                if member != system_id && layer.contains_key(member){
+                                    shifts.insert(*member);
+                                }
+                            }
+                        },
+                        RunOrder::AfterSet(set) => {
+                            if sets.get(set).is_some_and(|members| 
+                                members.iter().any(|member| member != system_id && layer.contains_key(member))
+                            ){
+                                shifts.insert(*system_id);
+                            }
+                        },
                     }
                 }
             }
@@ -305,7 +918,7 @@ impl StagesBuilder{
             }
 
             // Push a new layer and move all the shifted Systems from current layer to next layer
-            graph.push(HashMap::new());
+            graph.push(BTreeMap::new());
             
             for system_id in shifts.drain(){ // Clear the shifts while we're at it
                 let orders = graph[layer_id].remove(system_id).unwrap();
@@ -314,35 +927,41 @@ impl StagesBuilder{
         };
 
         // Now convert it into a graph without the extra data
-        let mut final_graph = Vec::new();
-
-        for mut layer in graph.drain(0..){
-            final_graph.push(Vec::new());
-            for (id, _) in layer.drain(){
-                final_graph.last_mut().unwrap().push(id);
-            }
-        }
-
-        final_graph
+        graph.into_iter()
+            .map(|layer| layer.into_keys().collect())
+            .collect()
     }
     /// Build the Stages for Dispatcher to use
-    fn build(mut self) -> Vec<Stage>{
+    fn build(mut self, sets: &HashMap<&'static str, Vec<&'static str>>) -> Vec<Stage>{
 
         let mut stages = Vec::new();
 
-        let graph = self.build_run_order_graph();
+        let graph = self.build_run_order_graph(sets);
 
         // We don't need to use `.iter()` as the final graph will not be used for anything else, we also own it anyway
         for layer in graph{
-            stages.push(Vec::new());
+            // Don't start a new Stage if the last one is still empty
+            if stages.last().is_none_or(|stage: &Stage| !stage.is_empty()){
+                stages.push(Vec::new());
+            }
             for system_id in layer{
+                // Unwrap: The graph is built from the Systems in this builder
+                let system = self.systems.remove(system_id).unwrap();
+
+                // Exclusive Systems get a Stage all to themselves
+                if system.exclusive(){
+                    if !stages.last().unwrap().is_empty(){
+                        stages.push(Vec::new());
+                    }
+                    stages.last_mut().unwrap().push(system);
+                    stages.push(Vec::new());
+                    continue
+                }
+
                 // Don't like that I have to use so many unwraps
                 stages.last_mut()
                     .unwrap()
-                    .push(
-                        self.systems.remove(system_id)
-                        .unwrap()
-                    );
+                    .push(system);
 
                 if stages.last().unwrap().len() == MAX_SYS_PER_STAGE{
                     stages.push(Vec:: new());
@@ -350,21 +969,37 @@ impl StagesBuilder{
             }
         }
 
+        // A Stage left open at the end never got any Systems,
+        // this is also what turns a section without Systems into no Stages at all
+        if stages.last().is_some_and(|stage| stage.is_empty()){
+            stages.pop();
+        }
+
         stages
     }
 }
 
 /// # Run Order enum
 /// Specifies when a System should be run
+/// 
+/// `Before` and `After` order against a single System by it's ID,  
+/// `BeforeSet` and `AfterSet` order against every member of a System Set, see `DispatcherBuilder::add_to_set`
+/// 
+/// `Before` and `After` can only target Systems of the same `SystemType`, the Dispatcher Builder panics otherwise.  
+/// Sets can span several types, their members in other types are simply ignored
 pub enum RunOrder{
     Before(&'static str),
     After(&'static str),
+    BeforeSet(&'static str),
+    AfterSet(&'static str)
 }
 impl RunOrder{
     pub fn value(&self) -> &'static str{
         match *self{
             RunOrder::Before(val) => val,
             RunOrder::After(val) => val,
+            RunOrder::BeforeSet(val) => val,
+            RunOrder::AfterSet(val) => val,
         }
     }
 }
@@ -391,14 +1026,17 @@ impl RunOrder{
 /// 
 /// `Singlefire` Systems are ran at the will of other Systems  
 /// To execute a System like this, another System needs to send a Trigger it through TriggerWriter  
-/// You cannot daisy-chain Singlefires within same Tick, any Singlefires triggered by current Tick Singlefires will only be executed on the next Tick
+/// You cannot daisy-chain Singlefires within same Tick, any Singlefires triggered by current Tick Singlefires will only be executed on the next Tick,  
+/// unless they're triggered via `TriggerWriter::send_immediate`
 /// 
 /// `EventResponder` Systems are ran when their respective Event is present within the Read Buffer, signified by the ID  
 /// They're effectively Logic Systems that listen for Events only and declutter the main Logic loop
 /// 
 /// `Postprocessor` Systems are ran at the end of every frame  
 /// They are typically output Systems like Audio, Outgoing Network and Rendering
-#[derive(Default)]
+/// 
+/// Each type runs in it's own part of the loop, so Run Orders can't move a System between them
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum SystemType{
     Preprocessor,
     #[default]
@@ -408,13 +1046,22 @@ pub enum SystemType{
     Postprocessor
 }
 
+/// # System Section
+/// Identifies the parts of the Execution Loop that are built into Stages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemSection{
+    Preprocessor,
+    Logic,
+    Postprocessor
+}
+
 #[cfg(test)]
 mod tests{
     use super::*;
     use crate::ECS::fetch::Request;
     mod loops{
         use super::*;
-        use crate::ECS::resource::DeltaT;
+        use crate::ECS::resource::{DeltaT, LogicPause, Resource};
         use crate::ECS::fetch::WriteEvent;
         use crate::ECS::events::ExitApp;
 
@@ -458,243 +1105,1826 @@ mod tests{
             builder.add::<Staller>();
             builder.add::<Logic>();
 
-            let mut dispatcher = builder.build();
+            let mut dispatcher = builder.build(&mut world);
             dispatcher.dispatch(&mut world);
         }
-    }
-    mod deps{
-        use super::*;
 
-        struct BackDep;
-        struct Dependee;
-        struct ForwDep;
-
-        impl System for BackDep{
-            type Data<'a> = ();
-            const ID: &'static str = "BackDep";
-            const TYPE: SystemType = SystemType::Preprocessor;
+        struct Deltas(Vec<u128>);
+        impl Resource for Deltas{
+            const ID: &'static str = "Deltas";
         
             fn new() -> Self {
-                Self
-            }
-            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
-                
+                Self(Vec::new())
             }
         }
-        impl System for Dependee{
-            type Data<'a> = ();
-            const ID: &'static str = "Dependee";
-            const DEPENDS: &'static [&'static str] = &[BackDep::ID, ForwDep::ID];
-
+        struct Integrator;
+        impl System for Integrator{
+            type Data<'a> = (&'a DeltaT, &'a mut Deltas);
+            const ID: &'static str = "Integrator";
+            const TYPE: SystemType = SystemType::Logic;
+        
             fn new() -> Self {
                 Self
             }
-        
-            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
-                
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                let delta = data.0.delta_logic();
+                data.1.0.push(delta);
             }
         }
-        impl System for ForwDep{
-            type Data<'a> = ();
-            const ID: &'static str = "ForwDep";
+
+        #[test]
+        fn test_fixed_delta(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Integrator>();
+            builder.register_res::<Deltas>();
+
+            let mut dispatcher = builder.build(&mut world);
+            let mut accumulator = Duration::ZERO;
+
+            // Jittery frames, 200ms in total
+            for frame in [3, 47, 12, 61, 8, 29, 40]{
+                dispatcher.step_logic(&mut world, &mut accumulator, Duration::from_millis(frame));
+            }
+
+            let deltas = &world.fetch_res::<Deltas>().0;
+            assert!(deltas.len() == 4);
+            assert!(deltas.iter().all(|delta| *delta == TICKRATE.as_micros()));
+        }
+
+        struct Ping;
+        impl Event for Ping{
+            const ID: &'static str = "Ping";
+        }
+        struct Pinger;
+        impl System for Pinger{
+            type Data<'a> = (WriteEvent<Ping>, &'a mut Deltas);
+            const ID: &'static str = "Pinger";
         
             fn new() -> Self {
                 Self
             }
-        
-            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
-                
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                // Record how many Pings we see from the previous Tick
+                let seen = data.0.prev_event_count() as u128;
+                data.1.0.push(seen);
+                data.0.send(Ping);
             }
         }
 
         #[test]
-        fn test_deps(){
+        fn test_event_swap(){
+            let mut world = World::new();
+
             let mut builder = Dispatcher::new();
-            builder.add::<Dependee>();
-            builder.add::<BackDep>();
-            builder.add::<ForwDep>();
+            builder.add::<Pinger>();
+            builder.register_res::<Deltas>();
+            builder.register_event::<Ping>();
 
-            let _dispatcher = builder.build();
+            let mut dispatcher = builder.build(&mut world);
+
+            dispatcher.logic_tick(&mut world);
+            dispatcher.logic_tick(&mut world);
+            dispatcher.logic_tick(&mut world);
+
+            assert!(world.fetch_res::<Deltas>().0 == [0, 1, 1]);
         }
-        #[test]
-        #[should_panic]
-        fn test_missing(){
-            let mut builder = Dispatcher::new();
-            builder.add::<Dependee>();
 
-            let _dispatcher = builder.build();
+        struct Counts{
+            logic: u32,
+            throttled: u32,
+            postproc: u32
         }
-    }
+        impl Resource for Counts{
+            const ID: &'static str = "Counts";
+        
+            fn new() -> Self {
+                Self{
+                    logic: 0,
+                    throttled: 0,
+                    postproc: 0
+                }
+            }
+        }
+        struct CountLogic;
+        struct CountPostproc;
+        struct CountThrottled;
+        impl System for CountLogic{
+            type Data<'a> = &'a mut Counts;
+            const ID: &'static str = "CountLogic";
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.logic += 1;
+            }
+        }
+        impl System for CountPostproc{
+            type Data<'a> = &'a mut Counts;
+            const ID: &'static str = "CountPostproc";
+            const TYPE: SystemType = SystemType::Postprocessor;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.postproc += 1;
+            }
+        }
+        impl System for CountThrottled{
+            type Data<'a> = &'a mut Counts;
+            const ID: &'static str = "CountThrottled";
+            const TICK_INTERVAL: u64 = 3;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.throttled += 1;
+            }
+        }
+
+        struct PauseMenu;
+        impl System for PauseMenu{
+            type Data<'a> = (&'a DeltaT, &'a mut LogicPause);
+            const ID: &'static str = "PauseMenu";
+            const TYPE: SystemType = SystemType::Postprocessor;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                // Open the menu on frame 2, close it on frame 4
+                match data.0.frame(){
+                    2 => data.1.set_paused(true),
+                    4 => data.1.set_paused(false),
+                    _ => ()
+                }
+            }
+        }
+
+        #[test]
+        fn test_logic_paused(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<CountLogic>();
+            builder.add::<CountPostproc>();
+            builder.add::<PauseMenu>();
+            builder.register_res::<Counts>();
+
+            let mut dispatcher = builder.build(&mut world);
+            let mut accumulator = Duration::ZERO;
+
+            for _ in 0..3{
+                assert!(dispatcher.frame(&mut world, &mut accumulator, TICKRATE).is_none());
+            }
+            assert!(world.fetch_res::<LogicPause>().is_paused());
+
+            for _ in 0..3{
+                assert!(dispatcher.frame(&mut world, &mut accumulator, TICKRATE).is_none());
+            }
+            assert!(!world.fetch_res::<LogicPause>().is_paused());
+
+            // Frames 3 and 4 ran without Logic
+            let counts = world.fetch_res::<Counts>();
+            assert!(counts.logic == 4);
+            assert!(counts.postproc == 6);
+        }
+        struct Fired(Vec<(&'static str, u64)>);
+        impl Resource for Fired{
+            const ID: &'static str = "Fired";
+        
+            fn new() -> Self {
+                Self(Vec::new())
+            }
+        }
+        struct FireLogic;
+        struct FirePostproc;
+        struct First;
+        struct Second;
+        struct Late;
+        impl System for FireLogic{
+            type Data<'a> = (&'a DeltaT, crate::ECS::fetch::Triggers);
+            const ID: &'static str = "FireLogic";
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                if data.0.logic_frame() == 0{
+                    data.1.send_immediate(First::ID);
+                }
+            }
+        }
+        impl System for FirePostproc{
+            type Data<'a> = (&'a DeltaT, crate::ECS::fetch::Triggers);
+            const ID: &'static str = "FirePostproc";
+            const TYPE: SystemType = SystemType::Postprocessor;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                if data.0.frame() == 0{
+                    data.1.send_immediate(Late::ID);
+                }
+            }
+        }
+        impl System for First{
+            type Data<'a> = (&'a mut Fired, &'a DeltaT, crate::ECS::fetch::Triggers);
+            const ID: &'static str = "First";
+            const TYPE: SystemType = SystemType::Singlefire;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                let tick = data.1.logic_frame();
+                data.0.0.push((Self::ID, tick));
+                // Chained within the same Tick
+                data.2.send_immediate(Second::ID);
+            }
+        }
+        impl System for Second{
+            type Data<'a> = (&'a mut Fired, &'a DeltaT);
+            const ID: &'static str = "Second";
+            const TYPE: SystemType = SystemType::Singlefire;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                let tick = data.1.logic_frame();
+                data.0.0.push((Self::ID, tick));
+            }
+        }
+        impl System for Late{
+            type Data<'a> = (&'a mut Fired, &'a DeltaT);
+            const ID: &'static str = "Late";
+            const TYPE: SystemType = SystemType::Singlefire;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                let tick = data.1.logic_frame();
+                data.0.0.push((Self::ID, tick));
+            }
+        }
+
+        #[test]
+        fn test_immediate_triggers(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<FireLogic>();
+            builder.add::<FirePostproc>();
+            builder.add::<First>();
+            builder.add::<Second>();
+            builder.add::<Late>();
+            builder.register_res::<Fired>();
+
+            let mut dispatcher = builder.build(&mut world);
+            let mut accumulator = Duration::ZERO;
+
+            // One Logic Tick per frame
+            for _ in 0..2{
+                assert!(dispatcher.frame(&mut world, &mut accumulator, TICKRATE).is_none());
+            }
+
+            // Logic and Singlefire Triggers fire the same Tick, Postprocessor ones on the next
+            assert!(world.fetch_res::<Fired>().0 == [("First", 0), ("Second", 0), ("Late", 1)]);
+        }
+
+        struct Received(Vec<(usize, Option<u8>)>);
+        impl Resource for Received{
+            const ID: &'static str = "Received";
+        
+            fn new() -> Self {
+                Self(Vec::new())
+            }
+        }
+        struct PayloadSender;
+        struct PayloadReceiver;
+        impl System for PayloadSender{
+            type Data<'a> = (&'a DeltaT, crate::ECS::fetch::Triggers);
+            const ID: &'static str = "PayloadSender";
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                match data.0.logic_frame(){
+                    // The u16 never gets taken
+                    0 => {
+                        data.1.send_with(PayloadReceiver::ID, 7u8);
+                        data.1.send_with(PayloadReceiver::ID, 8u16);
+                    }
+                    1 => data.1.send(PayloadReceiver::ID),
+                    _ => ()
+                }
+            }
+        }
+        impl System for PayloadReceiver{
+            type Data<'a> = (crate::ECS::fetch::TriggerPayloads, &'a mut Received);
+            const ID: &'static str = "PayloadReceiver";
+            const TYPE: SystemType = SystemType::Singlefire;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                let left = data.0.payload_count(Self::ID);
+                let payload = data.0.take::<u8>(Self::ID);
+                data.1.0.push((left, payload));
+            }
+        }
+
+        #[test]
+        fn test_payloads(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<PayloadSender>();
+            builder.add::<PayloadReceiver>();
+            builder.register_res::<Received>();
+
+            let mut dispatcher = builder.build(&mut world);
+            let mut accumulator = Duration::ZERO;
+
+            assert!(SystemWrapper::access(&PayloadReceiver::new()).contains(&Access::Triggers));
+
+            for _ in 0..3{
+                assert!(dispatcher.frame(&mut world, &mut accumulator, TICKRATE).is_none());
+            }
+
+            // The untaken u16 is gone by the next Tick
+            assert!(world.fetch_res::<Received>().0 == [(2, Some(7)), (1, None), (0, None)]);
+            assert!(world.take_trigger_payload::<u16>(PayloadReceiver::ID).is_none());
+        }
+        #[test]
+        fn test_tick_interval(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<CountLogic>();
+            builder.add::<CountThrottled>();
+            builder.register_res::<Counts>();
+
+            let mut dispatcher = builder.build(&mut world);
+
+            // Runs on Ticks 0, 3 and 6
+            for tick in 0..9{
+                dispatcher.lockstep_tick(&mut world);
+                assert!(world.fetch_res::<Counts>().throttled == tick / 3 + 1);
+            }
+
+            let counts = world.fetch_res::<Counts>();
+            assert!(counts.logic == 9);
+            assert!(counts.throttled == 3);
+        }
+        #[test]
+        fn test_current_tick(){
+            let mut world = World::new();
+            let mut dispatcher = Dispatcher::new().build(&mut world);
+
+            assert!(world.current_tick() == 0);
+            for tick in 1..=3{
+                dispatcher.logic_tick(&mut world);
+                assert!(world.current_tick() == tick);
+            }
+            assert!(world.current_tick() == world.fetch_res::<DeltaT>().logic_frame());
+        }
+        #[test]
+        fn test_auto_register(){
+            let mut world = World::new();
+            world.deregister_res::<DeltaT>();
+
+            let mut builder = Dispatcher::new();
+            builder.register_res::<Deltas>();
+            builder.add::<Integrator>();
+
+            let (mut dispatcher, report) = builder.build_with_report(&mut world);
+            assert!(report.warnings.contains(&BuildWarning::AutoRegistered(DeltaT::ID)));
+
+            dispatcher.logic_tick(&mut world);
+            assert!(world.fetch_res::<Deltas>().0.len() == 1);
+        }
+        #[test]
+        fn test_empty(){
+            let mut world = World::new();
+            let mut dispatcher = Dispatcher::new().build(&mut world);
+
+            assert!(dispatcher.stage_layout().iter().all(|(_, stages)| stages.is_empty()));
+
+            let mut accumulator = Duration::ZERO;
+            for _ in 0..10{
+                assert!(dispatcher.frame(&mut world, &mut accumulator, TICKRATE).is_none());
+            }
+            assert!(world.current_tick() == 10);
+            assert!(world.take_triggers().is_empty());
+            assert!(world.take_commands().is_empty());
+
+            // Exit handling still works with nothing to run
+            world.get_event_writer::<ExitApp>().send(ExitApp(0));
+            assert!(dispatcher.frame(&mut world, &mut accumulator, Duration::ZERO).is_some_and(|codes| *codes == [0]));
+        }
+        #[test]
+        fn test_commands(){
+            use crate::ECS::comp::Component;
+            use crate::ECS::entity::Token;
+            use crate::ECS::storage::DefaultStorage;
+            use crate::ECS::fetch::{Commands, Query};
+
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = DefaultStorage<Self>;
+
+                const ID: &'static str = "idkfa";
+            }
+            struct Seen(Vec<u8>);
+            impl Resource for Seen{
+                const ID: &'static str = "Seen";
+
+                fn new() -> Self {
+                    Self(Vec::new())
+                }
+            }
+            struct Targets(Vec<Token>);
+            impl Resource for Targets{
+                const ID: &'static str = "Targets";
+
+                fn new() -> Self {
+                    Self(Vec::new())
+                }
+            }
+
+            struct Spawner;
+            struct Watcher;
+            impl System for Spawner{
+                type Data<'a> = (Commands, Query<&'a idkfa>, &'a Targets);
+                const ID: &'static str = "Spawner";
+
+                fn new() -> Self {
+                    Self
+                }
+                fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                    let (commands, query, targets) = &mut *data;
+
+                    if query.iter().count() == 2{
+                        commands.spawn((idkfa(3),));
+                    }else{
+                        commands.entity(&targets.0[0]).insert(idkfa(5));
+                        commands.entity(&targets.0[1]).despawn();
+                    }
+                }
+            }
+            impl System for Watcher{
+                type Data<'a> = (Query<&'a idkfa>, &'a mut Seen);
+                const ID: &'static str = "Watcher";
+                const TYPE: SystemType = SystemType::Postprocessor;
+
+                fn new() -> Self {
+                    Self
+                }
+                fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                    let (query, seen) = &mut *data;
+                    seen.0 = query.iter().map(|kfa| kfa.0).collect();
+                }
+            }
+
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.register_comp::<idkfa>();
+            builder.register_res::<Seen>();
+            builder.register_res::<Targets>();
+            builder.add::<Spawner>();
+            builder.add::<Watcher>();
+            let mut dispatcher = builder.build(&mut world);
+
+            let first = world.spawn().with(idkfa(1)).finish();
+            let second = world.spawn().with(idkfa(2)).finish();
+            world.fetch_res_mut::<Targets>().0 = vec![first, second];
+
+            let mut accumulator = Duration::ZERO;
+
+            // The Postprocessor of the same frame already sees the spawned Entity
+            dispatcher.frame(&mut world, &mut accumulator, TICKRATE);
+            assert!(world.fetch_res::<Seen>().0 == [1, 2, 3]);
+
+            dispatcher.frame(&mut world, &mut accumulator, TICKRATE);
+            assert!(world.fetch_res::<Seen>().0 == [5, 3]);
+        }
+        #[test]
+        fn test_command_results(){
+            use crate::ECS::comp::Component;
+            use crate::ECS::commands::Ticket;
+            use crate::ECS::storage::{DefaultStorage, Storage};
+            use crate::ECS::fetch::{Commands, CommandResults};
+
+            struct idkfa;
+            impl Component for idkfa{
+                type STORAGE = DefaultStorage<Self>;
+
+                const ID: &'static str = "idkfa";
+            }
+            struct Redeemed(Vec<Option<usize>>);
+            impl Resource for Redeemed{
+                const ID: &'static str = "Redeemed";
+
+                fn new() -> Self {
+                    Self(Vec::new())
+                }
+            }
+
+            struct Requester(Option<Ticket<usize>>);
+            impl System for Requester{
+                type Data<'a> = (Commands, CommandResults, &'a mut Redeemed);
+                const ID: &'static str = "Requester";
+
+                fn new() -> Self {
+                    Self(None)
+                }
+                fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                    let (commands, results, redeemed) = &mut *data;
+
+                    match self.0.take(){
+                        Some(ticket) => redeemed.0.push(results.take(ticket)),
+                        None => self.0 = Some(commands.spawn_with_ticket((idkfa,)))
+                    }
+                }
+            }
+
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.register_comp::<idkfa>();
+            builder.register_res::<Redeemed>();
+            builder.add::<Requester>();
+            let mut dispatcher = builder.build(&mut world);
+
+            let mut accumulator = Duration::ZERO;
+
+            // Sent on the first Tick, redeemed on the second
+            for _ in 0..2{
+                dispatcher.frame(&mut world, &mut accumulator, TICKRATE);
+            }
+
+            let redeemed = world.fetch_res::<Redeemed>();
+            assert!(redeemed.0.len() == 1);
+            let id = redeemed.0[0].unwrap();
+            assert!(world.fetch::<idkfa>().get(&id).is_some());
+            assert!(world.get_result_reader().result_count() == 0);
+        }
+        #[test]
+        fn test_hooks(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.register_res::<Counts>();
+
+            let mut dispatcher = builder.build(&mut world);
+            dispatcher.on_tick(|world| world.fetch_res_mut::<Counts>().logic += 1);
+            dispatcher.on_frame_start(|world| world.fetch_res_mut::<Counts>().postproc += 1);
+            dispatcher.on_frame_end(|world| world.fetch_res_mut::<Counts>().postproc += 1);
+
+            let mut accumulator = Duration::ZERO;
+
+            // 2 frames, 3 Ticks
+            dispatcher.frame(&mut world, &mut accumulator, TICKRATE);
+            dispatcher.frame(&mut world, &mut accumulator, TICKRATE * 2);
+
+            let counts = world.fetch_res::<Counts>();
+            assert!(counts.logic == 3);
+            assert!(counts.postproc == 4);
+        }
+    }
+    mod deps{
+        use super::*;
+
+        struct BackDep;
+        struct Dependee;
+        struct ForwDep;
+
+        impl System for BackDep{
+            type Data<'a> = ();
+            const ID: &'static str = "BackDep";
+            const TYPE: SystemType = SystemType::Preprocessor;
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for Dependee{
+            type Data<'a> = ();
+            const ID: &'static str = "Dependee";
+            const DEPENDS: &'static [&'static str] = &[BackDep::ID, ForwDep::ID];
+
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for ForwDep{
+            type Data<'a> = ();
+            const ID: &'static str = "ForwDep";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+
+        #[test]
+        fn test_deps(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Dependee>();
+            builder.add::<BackDep>();
+            builder.add::<ForwDep>();
+
+            let _dispatcher = builder.build(&mut World::new());
+        }
+        #[test]
+        fn test_info(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Dependee>();
+            builder.add::<BackDep>();
+            builder.add::<ForwDep>();
+
+            let dispatcher = builder.build(&mut World::new());
+
+            let info = dispatcher.system_info(Dependee::ID).unwrap();
+            assert!(info.id == Dependee::ID);
+            assert!(info.depends == [BackDep::ID, ForwDep::ID]);
+            assert!(info.run_ord.is_empty());
+            assert!(matches!(info.sys_type, SystemType::Logic));
+
+            assert!(matches!(dispatcher.system_info(BackDep::ID).unwrap().sys_type, SystemType::Preprocessor));
+            assert!(dispatcher.system_info("Ghost").is_none());
+        }
+        #[test]
+        #[should_panic]
+        fn test_missing(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Dependee>();
+
+            let _dispatcher = builder.build(&mut World::new());
+        }
+    }
     mod run_ord{
         use super::*;
-        use crate::ECS::resource::Resource;
+        use crate::ECS::resource::Resource;
+        use crate::ECS::events::ExitApp;
+        use crate::ECS::fetch::WriteEvent;
+
+        struct SysA;
+        struct SysB;
+        struct SysC;
+
+        struct Runs{
+            a: bool,
+            b: bool,
+            c: bool
+        }
+
+        impl Resource for Runs{
+            const ID: &'static str = "Runs";
+        
+            fn new() -> Self {
+                Self{
+                    a: false,
+                    b: false,
+                    c: false,
+                }
+            }
+        }
+
+        impl System for SysA{
+            type Data<'a> = &'a mut Runs;
+            const ID: &'static str = "SysA";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::Before(SysC::ID)];
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.a = true;
+
+                assert!(data.a);
+                assert!(!data.b);
+                assert!(!data.c);
+            }
+        }
+
+        impl System for SysB{
+            type Data<'a> = &'a mut Runs;
+            const ID: &'static str = "SysB";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::After(SysA::ID)];
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.b = true;
+
+                assert!(data.a);
+                assert!(data.b);
+                assert!(!data.c);
+            }
+        }
+
+        impl System for SysC{
+            type Data<'a> = (&'a mut Runs, WriteEvent<ExitApp>);
+            const ID: &'static str = "SysC";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::After(SysB::ID)];
+
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.0.c = true;
+
+                assert!(data.0.a);
+                assert!(data.0.b);
+                assert!(data.0.c);
+
+                data.1.send(ExitApp(0));
+            }
+        }
+
+        #[test]
+        fn test(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.register_res::<Runs>();
+            builder.add::<SysA>();
+            builder.add::<SysB>();
+            builder.add::<SysC>();
+
+            let mut dispatcher = builder.build(&mut world);
+
+            dispatcher.dispatch(&mut world);
+        }
+
+        struct Early;
+        struct Misplaced;
+        struct Grouped;
+
+        impl System for Early{
+            type Data<'a> = ();
+            const ID: &'static str = "Early";
+            const TYPE: SystemType = SystemType::Preprocessor;
+
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for Misplaced{
+            type Data<'a> = ();
+            const ID: &'static str = "Misplaced";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::After(Early::ID)];
+
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for Grouped{
+            type Data<'a> = ();
+            const ID: &'static str = "Grouped";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::AfterSet("Input")];
+
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_cross_type(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Early>();
+            // SHOULD PANIC
+            builder.add::<Misplaced>();
+        }
+        #[test]
+        #[should_panic]
+        fn test_cross_type_reverse(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Misplaced>();
+            // SHOULD PANIC
+            builder.add::<Early>();
+        }
+        #[test]
+        fn test_cross_type_set(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Early>();
+            builder.add::<Grouped>();
+            builder.add_to_set(Early::ID, "Input");
+
+            let dispatcher = builder.build(&mut world);
+
+            assert!(dispatcher.execution_order() == [Early::ID, Grouped::ID]);
+        }
+    }
+    mod dynamic{
+        use super::*;
+        use crate::ECS::resource::Resource;
+
+        struct Order(Vec<&'static str>);
+        impl Resource for Order{
+            const ID: &'static str = "Order";
+        
+            fn new() -> Self {
+                Self(Vec::new())
+            }
+        }
+
+        struct Base;
+        struct Aftermath;
+        struct Orphan;
+        struct Early;
+
+        impl System for Base{
+            type Data<'a> = &'a mut Order;
+            const ID: &'static str = "Base";
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.0.push(Self::ID);
+            }
+        }
+        impl System for Aftermath{
+            type Data<'a> = &'a mut Order;
+            const ID: &'static str = "Aftermath";
+            const DEPENDS: &'static [&'static str] = &[Base::ID];
+            const RUNORD: &'static [RunOrder] = &[RunOrder::After(Base::ID)];
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.0.push(Self::ID);
+            }
+        }
+        impl System for Orphan{
+            type Data<'a> = ();
+            const ID: &'static str = "Orphan";
+            const DEPENDS: &'static [&'static str] = &["Missing"];
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for Early{
+            type Data<'a> = ();
+            const ID: &'static str = "Early";
+            const TYPE: SystemType = SystemType::Preprocessor;
+            const RUNORD: &'static [RunOrder] = &[RunOrder::Before(Base::ID)];
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+
+        #[test]
+        fn test(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Base>();
+            builder.register_res::<Order>();
+
+            let mut dispatcher = builder.build(&mut world);
+
+            dispatcher.lockstep_tick(&mut world);
+            assert!(dispatcher.add_system_dynamic::<Aftermath>().is_ok());
+            dispatcher.lockstep_tick(&mut world);
+
+            // Sorts before Base by ID, but the Run Order puts it after
+            assert!(world.fetch_res::<Order>().0 == ["Base", "Base", "Aftermath"]);
+            assert!(dispatcher.stage_layout()[1].1 == [vec!["Base"], vec!["Aftermath"]]);
+            assert!(dispatcher.system_info(Aftermath::ID).is_some());
+        }
+        #[test]
+        fn test_rejected(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Base>();
+            builder.register_res::<Order>();
+
+            let mut dispatcher = builder.build(&mut world);
+
+            assert!(dispatcher.add_system_dynamic::<Orphan>() == Err(DispatchError::MissingDependency{ system: "Orphan", dependency: "Missing" }));
+            assert!(dispatcher.add_system_dynamic::<Base>() == Err(DispatchError::ConflictingId("Base")));
+            assert!(dispatcher.add_system_dynamic::<Early>() == Err(DispatchError::CrossTypeRunOrder{ system: "Early", target: "Base" }));
+
+            // Nothing got added
+            assert!(dispatcher.execution_order() == ["Base"]);
+            assert!(dispatcher.system_info(Orphan::ID).is_none());
+        }
+
+        struct Loader(bool);
+        impl System for Loader{
+            type Data<'a> = &'a mut SystemQueue;
+            const ID: &'static str = "Loader";
+            const TYPE: SystemType = SystemType::Postprocessor;
+        
+            fn new() -> Self {
+                Self(false)
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                if !self.0{
+                    data.add::<Aftermath>();
+                    self.0 = true;
+                }
+            }
+        }
+
+        #[test]
+        fn test_queued(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Base>();
+            builder.add::<Loader>();
+            builder.register_res::<Order>();
+
+            let mut dispatcher = builder.build(&mut world);
+            let mut accumulator = Duration::ZERO;
+
+            // Queued in the first frame, runs from the second one on
+            for _ in 0..2{
+                assert!(dispatcher.frame(&mut world, &mut accumulator, TICKRATE).is_none());
+            }
+
+            assert!(world.fetch_res::<Order>().0 == ["Base", "Base", "Aftermath"]);
+            assert!(world.fetch_res::<SystemQueue>().is_empty());
+            assert!(dispatcher.stage_layout()[1].1 == [vec!["Base"], vec!["Aftermath"]]);
+        }
+    }
+    mod lockstep{
+        use super::*;
+        use crate::ECS::resource::Rng;
+        use crate::ECS::storage::{Storage, IterStorage, DefaultStorage};
+        use crate::ECS::fetch::Query;
+        use rand::Rng as _;
+
+        struct idkfa(u64);
+        impl Component for idkfa{
+            type STORAGE = DefaultStorage<Self>;
+
+            const ID: &'static str = "idkfa";
+        }
+
+        struct Drift;
+        struct Jitter;
+        impl System for Drift{
+            type Data<'a> = (Query<&'a mut idkfa>, &'a mut Rng);
+            const ID: &'static str = "Drift";
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                let (query, rng) = &mut *data;
+                for kfa in query.iter_mut(){
+                    kfa.0 += rng.gen_range(0..100);
+                }
+            }
+        }
+        impl System for Jitter{
+            type Data<'a> = (Query<&'a mut idkfa>, &'a mut Rng);
+            const ID: &'static str = "Jitter";
+        
+            fn new() -> Self {
+                Self
+            }
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                let (query, rng) = &mut *data;
+                for kfa in query.iter_mut(){
+                    kfa.0 = kfa.0.wrapping_mul(rng.gen_range(1..10));
+                }
+            }
+        }
+
+        fn simulate(ticks: usize) -> Vec<(usize, u64)>{
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.register_comp::<idkfa>();
+            builder.register_res::<Rng>();
+            builder.add::<Drift>();
+            builder.add::<Jitter>();
+            let mut dispatcher = builder.build(&mut world);
+
+            world.fetch_res_mut::<Rng>().reseed(42);
+            for _ in 0..8{
+                world.spawn().with(idkfa(0)).finish();
+            }
+
+            for _ in 0..ticks{
+                dispatcher.lockstep_tick(&mut world);
+            }
+
+            world.fetch::<idkfa>().iter_ordered().map(|(id, kfa)| (id, kfa.0)).collect()
+        }
+
+        #[test]
+        fn test(){
+            let first = simulate(20);
+            let second = simulate(20);
+
+            assert!(first.len() == 8);
+            assert!(first == second);
+        }
+    }
+    mod report{
+        use super::*;
+        use crate::ECS::events::Event;
+
+        struct Sys;
+        struct Responder;
+        struct Ping;
+        impl Event for Ping{
+            const ID: &'static str = "Ping";
+        }
+        impl System for Sys{
+            type Data<'a> = ();
+            const ID: &'static str = "System";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::Before("Ghost"), RunOrder::AfterSet("Ghosts")];
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
+        impl System for Responder{
+            type Data<'a> = ();
+            const ID: &'static str = "Responder";
+            const TYPE: SystemType = SystemType::EventResponder(Ping::ID);
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
+
+        #[test]
+        fn test(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Sys>();
+            builder.add::<Responder>();
+
+            let (_dispatcher, report) = builder.build_with_report(&mut World::new());
+
+            assert!(report.warnings.contains(&BuildWarning::UnknownRunOrder{ system: "System", target: "Ghost" }));
+            assert!(report.warnings.contains(&BuildWarning::UnknownRunOrder{ system: "System", target: "Ghosts" }));
+            assert!(report.warnings.contains(&BuildWarning::UnregisteredEvent{ system: "Responder", event: "Ping" }));
+        }
+        #[test]
+        fn test_parallelism(){
+            struct First;
+            struct Second;
+            struct Third;
+            struct Dependent;
+            struct Preproc;
+            impl System for First{
+                type Data<'a> = ();
+                const ID: &'static str = "First";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Second{
+                type Data<'a> = ();
+                const ID: &'static str = "Second";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Third{
+                type Data<'a> = ();
+                const ID: &'static str = "Third";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Dependent{
+                type Data<'a> = ();
+                const ID: &'static str = "Dependent";
+                const RUNORD: &'static [RunOrder] = &[RunOrder::After("First")];
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Preproc{
+                type Data<'a> = ();
+                const ID: &'static str = "Preproc";
+                const TYPE: SystemType = SystemType::Preprocessor;
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+
+            let mut builder = Dispatcher::new();
+            builder.add::<First>();
+            builder.add::<Second>();
+            builder.add::<Third>();
+            builder.add::<Dependent>();
+            builder.add::<Preproc>();
+
+            let (_dispatcher, report) = builder.build_with_report(&mut World::new());
+
+            assert!(report.parallelism == [
+                (SystemSection::Preprocessor, vec![1]),
+                (SystemSection::Logic, vec![3, 1]),
+                (SystemSection::Postprocessor, vec![])
+            ]);
+        }
+        #[test]
+        fn test_clean(){
+            let mut builder = Dispatcher::new();
+            builder.register_event::<Ping>();
+            builder.add::<Responder>();
+
+            let (_dispatcher, report) = builder.build_with_report(&mut World::new());
+
+            assert!(report.is_clean());
+        }
+        #[test]
+        fn test_sorted(){
+            let build = || {
+                let mut builder = Dispatcher::new();
+                builder.add::<Responder>();
+                builder.add::<Sys>();
+                builder.build_with_report(&mut World::new()).1
+            };
+
+            assert!(build().warnings == [
+                BuildWarning::UnknownRunOrder{ system: "System", target: "Ghost" },
+                BuildWarning::UnknownRunOrder{ system: "System", target: "Ghosts" },
+                BuildWarning::UnregisteredEvent{ system: "Responder", event: "Ping" }
+            ]);
+            assert!(build().warnings == build().warnings);
+        }
+        #[test]
+        fn test_isolated(){
+            use crate::ECS::resource::{DeltaT, Resource};
+
+            struct Score;
+            struct Unread;
+            impl Resource for Score{
+                const ID: &'static str = "Score";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+            impl Resource for Unread{
+                const ID: &'static str = "Unread";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+
+            struct Writer;
+            struct Reader;
+            struct Lonely;
+            struct Ordered;
+            struct Clock;
+            impl System for Writer{
+                type Data<'a> = &'a mut Score;
+                const ID: &'static str = "Writer";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Reader{
+                type Data<'a> = &'a Score;
+                const ID: &'static str = "Reader";
+                const TYPE: SystemType = SystemType::Postprocessor;
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Lonely{
+                type Data<'a> = &'a mut Unread;
+                const ID: &'static str = "Lonely";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Ordered{
+                type Data<'a> = &'a mut Unread;
+                const ID: &'static str = "Ordered";
+                const TYPE: SystemType = SystemType::Preprocessor;
+                const RUNORD: &'static [RunOrder] = &[RunOrder::Before("Elsewhere")];
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+            impl System for Clock{
+                type Data<'a> = &'a DeltaT;
+                const ID: &'static str = "Clock";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+            }
+
+            let build = |ordered: bool| {
+                let mut builder = Dispatcher::new();
+                builder.register_res::<Score>();
+                builder.register_res::<Unread>();
+                builder.add::<Writer>();
+                builder.add::<Reader>();
+                builder.add::<Lonely>();
+                builder.add::<Clock>();
+                if ordered{
+                    builder.add::<Ordered>();
+                }
+                builder.build_with_report(&mut World::new()).1
+            };
+
+            // Writer and Reader share Score, Clock only reads the engine's DeltaT
+            assert!(build(false).warnings == [BuildWarning::IsolatedSystem("Lonely")]);
+
+            // Ordered has a Run Order, and Lonely now shares Unread with it
+            assert!(build(true).warnings == [BuildWarning::UnknownRunOrder{ system: "Ordered", target: "Elsewhere" }]);
+        }
+    }
+    mod collision{
+        use super::*;
+
+        struct Sys;
+        struct Collision;
+
+        impl System for Sys{
+            type Data<'a> = ();
+            const ID: &'static str = "System";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for Collision{
+            type Data<'a> = ();
+            const ID: &'static str = "System";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+
+        struct Singlefire;
+        struct Preproc;
+        struct Postproc;
+
+        impl System for Singlefire{
+            type Data<'a> = ();
+            const ID: &'static str = "System";
+            const TYPE: SystemType = SystemType::Singlefire;
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for Preproc{
+            type Data<'a> = ();
+            const ID: &'static str = "Staller";
+            const TYPE: SystemType = SystemType::Preprocessor;
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        impl System for Postproc{
+            type Data<'a> = ();
+            const ID: &'static str = "Staller";
+            const TYPE: SystemType = SystemType::Postprocessor;
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Sys>();
+            // SHOULD PANIC
+            builder.add::<Collision>();
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: Conflicting system IDs System")]
+        fn test_logic_singlefire(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Sys>();
+            // SHOULD PANIC
+            builder.add::<Singlefire>();
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: Conflicting system IDs System")]
+        fn test_singlefire_logic(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Singlefire>();
+            // SHOULD PANIC
+            builder.add::<Sys>();
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: Conflicting system IDs Staller")]
+        fn test_preproc_postproc(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Preproc>();
+            // SHOULD PANIC
+            builder.add::<Postproc>();
+        }
+    }
+    mod overrides{
+        use super::*;
         use crate::ECS::events::ExitApp;
         use crate::ECS::fetch::WriteEvent;
 
-        struct SysA;
-        struct SysB;
-        struct SysC;
+        struct Sys;
+        struct Override;
 
-        struct Runs{
-            a: bool,
-            b: bool,
-            c: bool
+        impl System for Sys{
+            type Data<'a> = ();
+            const ID: &'static str = "System";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                panic!("Did not override!")
+            }
+        }
+        impl System for Override{
+            type Data<'a> = WriteEvent<ExitApp>;
+            const ID: &'static str = "System";
+            const OVERRIDE: bool = true;
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.send(ExitApp(0));
+            }
         }
 
-        impl Resource for Runs{
-            const ID: &'static str = "Runs";
+        struct OverridePostproc;
+        impl System for OverridePostproc{
+            type Data<'a> = WriteEvent<ExitApp>;
+            const ID: &'static str = "System";
+            const OVERRIDE: bool = true;
+            const TYPE: SystemType = SystemType::Postprocessor;
         
             fn new() -> Self {
-                Self{
-                    a: false,
-                    b: false,
-                    c: false,
-                }
+                Self
+            }
+        
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.send(ExitApp(0));
             }
         }
 
+        #[test]
+        fn test(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Sys>();
+            builder.add::<Override>();
+
+            let mut dispatcher = builder.build(&mut world);
+            dispatcher.dispatch(&mut world);
+        }
+        #[test]
+        fn test_different_type(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Sys>();
+            builder.add::<OverridePostproc>();
+
+            let mut dispatcher = builder.build(&mut world);
+
+            // The overriden Logic System is gone entirely
+            assert!(dispatcher.execution_order() == ["System"]);
+            assert!(dispatcher.system_info("System").unwrap().sys_type == &SystemType::Postprocessor);
+            dispatcher.dispatch(&mut world);
+        }
+    }
+    mod layout{
+        use super::*;
+
+        struct SysA;
+        struct SysB;
+        struct SysC;
+
         impl System for SysA{
-            type Data<'a> = &'a mut Runs;
+            type Data<'a> = ();
             const ID: &'static str = "SysA";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::Before(SysB::ID)];
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
+        impl System for SysB{
+            type Data<'a> = ();
+            const ID: &'static str = "SysB";
             const RUNORD: &'static [RunOrder] = &[RunOrder::Before(SysC::ID)];
         
             fn new() -> Self {
                 Self
             }
         
-            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
-                data.a = true;
-
-                assert!(data.a);
-                assert!(!data.b);
-                assert!(!data.c);
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
+        impl System for SysC{
+            type Data<'a> = ();
+            const ID: &'static str = "SysC";
+        
+            fn new() -> Self {
+                Self
             }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
         }
 
-        impl System for SysB{
-            type Data<'a> = &'a mut Runs;
-            const ID: &'static str = "SysB";
-            const RUNORD: &'static [RunOrder] = &[RunOrder::After(SysA::ID)];
+        #[test]
+        fn test(){
+            let mut builder = Dispatcher::new();
+            builder.add::<SysC>();
+            builder.add::<SysA>();
+            builder.add::<SysB>();
+
+            let dispatcher = builder.build(&mut World::new());
+            let layout = dispatcher.stage_layout();
+
+            assert!(layout.len() == 3);
+            assert!(layout[1] == (SystemSection::Logic, vec![vec!["SysA"], vec!["SysB"], vec!["SysC"]]));
+        }
+
+        struct Late;
+        impl System for Late{
+            type Data<'a> = ();
+            const ID: &'static str = "Late";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::AfterSet("Group")];
         
             fn new() -> Self {
                 Self
             }
         
-            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
-                data.b = true;
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
+        struct Unordered;
+        impl System for Unordered{
+            type Data<'a> = ();
+            const ID: &'static str = "Unordered";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
 
-                assert!(data.a);
-                assert!(data.b);
-                assert!(!data.c);
+        #[test]
+        fn test_execution_order(){
+            fn build() -> Dispatcher{
+                let mut builder = Dispatcher::new();
+                builder.add::<Unordered>();
+                builder.add::<SysC>();
+                builder.add::<Late>();
+                builder.add::<ExclA>();
+                builder.add::<SysB>();
+                builder.add::<SysA>();
+                builder.add_to_set(SysA::ID, "Group");
+                builder.build(&mut World::new())
             }
+
+            let order = build().execution_order();
+
+            assert!(order.len() == 6);
+            assert!(order == build().execution_order());
+
+            let position = |id| order.iter().position(|sys| *sys == id).unwrap();
+            assert!(position(SysA::ID) < position(SysB::ID));
+            assert!(position(SysB::ID) < position(SysC::ID));
+            assert!(position(SysA::ID) < position(Late::ID));
         }
+        #[test]
+        fn test_sets(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Late>();
+            builder.add::<Unordered>();
+            builder.add::<ExclA>();
+            builder.add::<ExclB>();
+            builder.add::<SysC>();
+            builder.add_to_set(ExclA::ID, "Group");
+            builder.add_to_set(ExclB::ID, "Group");
+            builder.add_to_set(SysC::ID, "Group");
 
-        impl System for SysC{
-            type Data<'a> = (&'a mut Runs, WriteEvent<ExitApp>);
-            const ID: &'static str = "SysC";
-            const RUNORD: &'static [RunOrder] = &[RunOrder::After(SysB::ID)];
+            let (dispatcher, report) = builder.build_with_report(&mut World::new());
+            assert!(report.is_clean());
+
+            let (_, logic) = &dispatcher.stage_layout()[1];
+            let stage_of = |id| logic.iter().position(|stage| stage.contains(&id)).unwrap();
 
+            for member in [ExclA::ID, ExclB::ID, SysC::ID]{
+                assert!(stage_of(Late::ID) > stage_of(member));
+            }
+            assert!(stage_of(Unordered::ID) < stage_of(Late::ID));
+        }
+
+        struct ExclA;
+        struct ExclB;
+        impl System for ExclA{
+            type Data<'a> = ();
+            const ID: &'static str = "ExclA";
+            const EXCLUSIVE: bool = true;
+        
             fn new() -> Self {
                 Self
             }
         
-            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
-                data.0.c = true;
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
+        impl System for ExclB{
+            type Data<'a> = ();
+            const ID: &'static str = "ExclB";
+            const EXCLUSIVE: bool = true;
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
+        }
 
-                assert!(data.0.a);
-                assert!(data.0.b);
-                assert!(data.0.c);
+        #[test]
+        fn test_exclusive(){
+            let mut builder = Dispatcher::new();
+            builder.add::<ExclA>();
+            builder.add::<SysC>();
+            builder.add::<ExclB>();
 
-                data.1.send(ExitApp(0));
+            let dispatcher = builder.build(&mut World::new());
+            let (_, logic) = &dispatcher.stage_layout()[1];
+
+            for stage in logic.iter(){
+                if stage.contains(&"ExclA") || stage.contains(&"ExclB"){
+                    assert!(stage.len() == 1);
+                }
+            }
+            assert!(logic.iter().flatten().count() == 3);
+        }
+    }
+    mod exclusive{
+        use super::*;
+        use crate::ECS::world::World;
+
+        struct Spawner;
+        impl ExclusiveSystem for Spawner{
+            const ID: &'static str = "Spawner";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, world: &mut World) {
+                world.spawn().finish();
+                world.spawn().finish();
+            }
+        }
+        struct Plain;
+        impl System for Plain{
+            type Data<'a> = ();
+            const ID: &'static str = "Plain";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::After(Spawner::ID)];
+        
+            fn new() -> Self {
+                Self
             }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
         }
 
         #[test]
         fn test(){
             let mut world = World::new();
-            world.register_res::<Runs>();
 
             let mut builder = Dispatcher::new();
-            builder.add::<SysA>();
-            builder.add::<SysB>();
-            builder.add::<SysC>();
+            builder.add_exclusive::<Spawner>();
+            builder.add::<Plain>();
 
-            let mut dispatcher = builder.build();
+            let mut dispatcher = builder.build(&mut world);
+            assert!(dispatcher.stage_layout()[1].1 == vec![vec!["Spawner"], vec!["Plain"]]);
 
-            dispatcher.dispatch(&mut world);
+            dispatcher.logic_tick(&mut world);
+            assert!(world.get_entities().len() == 2);
+        }
+        #[test]
+        #[should_panic]
+        fn test_collision(){
+            let mut builder = Dispatcher::new();
+            builder.add_exclusive::<Spawner>();
+            builder.add_exclusive::<Spawner>();
         }
     }
-    mod collision{
+    mod resilient{
         use super::*;
+        use crate::ECS::resource::Resource;
 
-        struct Sys;
-        struct Collision;
+        struct Runs(u32);
+        impl Resource for Runs{
+            const ID: &'static str = "Runs";
+        
+            fn new() -> Self {
+                Self(0)
+            }
+        }
 
-        impl System for Sys{
+        struct Faulty;
+        struct Healthy;
+        impl System for Faulty{
             type Data<'a> = ();
-            const ID: &'static str = "System";
+            const ID: &'static str = "Faulty";
         
             fn new() -> Self {
                 Self
             }
-        
             fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
-                
+                panic!("Faulty System")
             }
         }
-        impl System for Collision{
-            type Data<'a> = ();
-            const ID: &'static str = "System";
+        impl System for Healthy{
+            type Data<'a> = &'a mut Runs;
+            const ID: &'static str = "Healthy";
+            const RUNORD: &'static [RunOrder] = &[RunOrder::After(Faulty::ID)];
         
             fn new() -> Self {
                 Self
             }
-        
-            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
-                
+            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                data.0 += 1;
             }
         }
 
         #[test]
-        #[should_panic]
         fn test(){
+            let mut world = World::new();
+
             let mut builder = Dispatcher::new();
-            builder.add::<Sys>();
-            builder.add::<Collision>();
+            builder.add::<Faulty>();
+            builder.add::<Healthy>();
+            builder.register_res::<Runs>();
+
+            let mut dispatcher = builder.build(&mut world);
+            dispatcher.set_resilient(true);
+
+            dispatcher.logic_tick(&mut world);
+            dispatcher.logic_tick(&mut world);
+
+            assert!(dispatcher.disabled_systems().contains(Faulty::ID));
+            assert!(dispatcher.disabled_systems().len() == 1);
+            assert!(world.fetch_res::<Runs>().0 == 2);
+        }
+        #[test]
+        #[should_panic(expected = "Faulty System")]
+        fn test_not_resilient(){
+            let mut world = World::new();
+
+            let mut builder = Dispatcher::new();
+            builder.add::<Faulty>();
+
+            let mut dispatcher = builder.build(&mut world);
+
+            // SHOULD PANIC
+            dispatcher.logic_tick(&mut world);
         }
     }
-    mod overrides{
+    mod registration{
         use super::*;
-        use crate::ECS::events::ExitApp;
-        use crate::ECS::fetch::WriteEvent;
-
-        struct Sys;
-        struct Override;
 
-        impl System for Sys{
-            type Data<'a> = ();
-            const ID: &'static str = "System";
+        struct idkfa;
+        impl Resource for idkfa{
+            const ID: &'static str = "idkfa";
         
             fn new() -> Self {
                 Self
             }
-        
-            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
-                panic!("Did not override!")
-            }
         }
-        impl System for Override{
-            type Data<'a> = WriteEvent<ExitApp>;
+
+        struct Sys;
+        impl System for Sys{
+            type Data<'a> = &'a idkfa;
             const ID: &'static str = "System";
-            const OVERRIDE: bool = true;
         
             fn new() -> Self {
                 Self
             }
         
-            fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
-                data.send(ExitApp(0));
-            }
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {}
         }
 
         #[test]
@@ -702,11 +2932,20 @@ mod tests{
             let mut world = World::new();
 
             let mut builder = Dispatcher::new();
+            builder.register_res::<idkfa>();
             builder.add::<Sys>();
-            builder.add::<Override>();
 
-            let mut dispatcher = builder.build();
-            dispatcher.dispatch(&mut world);
+            let _dispatcher = builder.build(&mut world);
+
+            assert!(world.has_res(idkfa::ID));
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: System System requests Resource idkfa, which is not registered in the World")]
+        fn test_unregistered(){
+            let mut builder = Dispatcher::new();
+            builder.add::<Sys>();
+
+            let _dispatcher = builder.build(&mut World::new());
         }
     }
 }
\ No newline at end of file
@@ -1,16 +1,18 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::ops::Range;
 
 use super::comp::Component;
 use super::storage::Storage;
 use super::world::World;
 
-type EntityHash = u32;
+pub(crate) type EntityHash = u32;
 
 /// # Entity struct
 /// Identifies a single Entity within the World
 /// 
 /// Stores it's own ID and Hash for collision checks, 
 /// as well as what Components it has on the given frame
+#[derive(Clone)]
 pub struct Entity{
     id: usize,
     hash: EntityHash
@@ -18,9 +20,13 @@ pub struct Entity{
 impl Entity{
     /// Create a new Entity with given ID
     pub(crate) fn new(id: usize) -> Self{
+        Self::with_hash(id, rand::random())
+    }
+    /// Create an Entity with given ID and Hash, for bringing back one that existed before
+    pub(crate) fn with_hash(id: usize, hash: EntityHash) -> Self{
         Self{
             id,
-            hash: rand::random()
+            hash
         }
     }
     /// Get a Token for this Entity
@@ -41,6 +47,94 @@ impl Entity{
     }
 }
 
+/// # Free IDs
+/// The set of IDs freed up for reuse by new Entities
+/// 
+/// Keeps them as ranges, so freeing a huge span of IDs at once, e.g. by `World::spawn_at`, stays cheap
+#[derive(Clone, Default)]
+pub(crate) struct FreeIds{
+    // Start of each range -> its exclusive end, ranges never overlap or touch
+    pub(crate) ranges: BTreeMap<usize, usize>
+}
+impl FreeIds{
+    pub(crate) fn new() -> Self{
+        Self::default()
+    }
+    /// Free a single ID
+    pub(crate) fn insert(&mut self, id: usize){
+        self.insert_range(id..id + 1);
+    }
+    /// Free every ID in the range
+    pub(crate) fn insert_range(&mut self, range: Range<usize>){
+        if range.is_empty(){
+            return
+        }
+        let (mut start, mut end) = (range.start, range.end);
+
+        // Merge with the range reaching up to our start
+        if let Some((&prev_start, &prev_end)) = self.ranges.range(..=start).next_back()
+        && prev_end >= start{
+            start = prev_start;
+            end = end.max(prev_end);
+            self.ranges.remove(&prev_start);
+        }
+        // Swallow every range starting within ours
+        while let Some((&next_start, &next_end)) = self.ranges.range(start..=end).next(){
+            end = end.max(next_end);
+            self.ranges.remove(&next_start);
+        }
+
+        self.ranges.insert(start, end);
+    }
+    /// Take the ID back out, as it's now in use
+    pub(crate) fn remove(&mut self, id: usize){
+        let Some((&start, &end)) = self.ranges.range(..=id).next_back() else{
+            return
+        };
+        if id >= end{
+            return
+        }
+
+        self.ranges.remove(&start);
+        if start < id{
+            self.ranges.insert(start, id);
+        }
+        if id + 1 < end{
+            self.ranges.insert(id + 1, end);
+        }
+    }
+    /// Take the lowest free ID
+    pub(crate) fn pop_first(&mut self) -> Option<usize>{
+        let (start, end) = self.ranges.pop_first()?;
+        if start + 1 < end{
+            self.ranges.insert(start + 1, end);
+        }
+        Some(start)
+    }
+    /// Check if the ID is free
+    pub(crate) fn contains(&self, id: &usize) -> bool{
+        self.ranges.range(..=id).next_back().is_some_and(|(_, end)| id < end)
+    }
+    /// Check if there are no free IDs at all
+    pub(crate) fn is_empty(&self) -> bool{
+        self.ranges.is_empty()
+    }
+}
+
+/// # Entity ID Strategy
+/// Specifies how the World hands out IDs to new Entities
+/// 
+/// `ReuseLowest` reuses the lowest ID freed by a despawn first, this is the default
+/// 
+/// `AlwaysIncrement` always hands out a fresh ID, freed IDs are never reused.  
+/// Useful when stale IDs must never point to a different Entity, such as in networking
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdStrategy{
+    #[default]
+    ReuseLowest,
+    AlwaysIncrement
+}
+
 /// # Entity Token
 /// A "reference" to a specific Entity within the World
 /// 
@@ -55,6 +149,16 @@ pub struct Token{
     valid: bool
 }
 impl Token{
+    /// Rebuild a Token from a saved ID and Hash, such as one stored in a serialized Component
+    /// 
+    /// The Token starts out as valid, use `refresh` to check it against the World
+    pub fn from_parts(id: usize, hash: EntityHash) -> Self{
+        Self{
+            id,
+            hash,
+            valid: true
+        }
+    }
     /// Read the tracked Entity's ID
     pub fn id(&self) -> usize{
         self.id
@@ -73,10 +177,24 @@ impl Token{
     /// If the IDs don't match, it doesn't do anything
     pub fn validate(&mut self, entity: &Entity) -> bool{
         if self.id == entity.id(){
-            self.valid = self.hash == entity.hash();
+            self.valid = self.matches(entity);
         }
         self.valid
     }
+    /// Check if the Token tracks the given Entity
+    /// 
+    /// Unlike `validate`, this does not touch the `valid` flag
+    pub fn matches(&self, entity: &Entity) -> bool{
+        self.id == entity.id() && self.hash == entity.hash()
+    }
+    /// Check if the Token is still valid by looking it's Entity up in the World
+    /// 
+    /// Same as `Query::validate_token`, but doesn't need a Query.  
+    /// Updates it's own `valid` flag and returns it
+    pub fn refresh(&mut self, world: &World) -> bool{
+        self.valid = world.is_alive(self);
+        self.valid
+    }
 }
 
 /// # Entity Builder
@@ -89,11 +207,26 @@ pub struct EntityBuilder<'a>{
 }
 impl<'a> EntityBuilder<'a>{
     /// Add a specified Component to the current Entity
+    /// 
+    /// Adding the same Component twice panics in debug builds, as it's almost always a mistake
     pub fn with<T: Component>(mut self, comp: T) -> Self{
+        debug_assert!(!self.components.contains(T::ID), "ERROR: Component {} was added to Entity {} twice", T::ID, self.entity.id());
+
         self.world_ref.fetch_mut::<T>().insert(self.entity.id(), comp);
         self.components.insert(T::ID);
         self
     }
+    /// Add a Component made from the current Entity's Token
+    /// 
+    /// Meant for Components that refer back to their own Entity, without having to patch them in after spawning
+    pub fn with_self<T: Component>(self, f: impl FnOnce(Token) -> T) -> Self{
+        let comp = f(self.entity);
+        self.with(comp)
+    }
+    /// Add a whole Bundle of Components to the current Entity
+    pub fn with_bundle<B: Bundle>(self, bundle: B) -> Self{
+        bundle.insert(self)
+    }
     /// Get the list of Components added to the Entity
     pub fn components(&self) -> &HashSet<&'static str>{
         &self.components
@@ -107,5 +240,44 @@ impl<'a> EntityBuilder<'a>{
         self.entity
     }
     /// "Finish" the building process
-    pub fn finish(self){}
-}
\ No newline at end of file
+    /// 
+    /// Returns the Token for the built Entity
+    pub fn finish(self) -> Token{
+        self.entity
+    }
+}
+
+/// # Component Bundle trait
+/// A set of Components that get added to an Entity together
+/// 
+/// It is implemented by default on Tuples of Components up to 12 elements,  
+/// use a 1-element Tuple `(Component,)` for a single Component
+pub trait Bundle{
+    /// Add every Component of this Bundle to the Entity being built
+    fn insert<'a>(self, builder: EntityBuilder<'a>) -> EntityBuilder<'a>;
+}
+
+macro_rules! bundle_impl {
+    ($($x:tt), *) => {
+        #[allow(non_snake_case)]
+        impl<$($x: Component), *> Bundle for ($($x,)*){
+            fn insert<'a>(self, builder: EntityBuilder<'a>) -> EntityBuilder<'a>{
+                let ($($x,)*) = self;
+                builder$(.with($x))*
+            }
+        }
+    }
+}
+
+bundle_impl!(A);
+bundle_impl!(A, B);
+bundle_impl!(A, B, C);
+bundle_impl!(A, B, C, D);
+bundle_impl!(A, B, C, D, E);
+bundle_impl!(A, B, C, D, E, F);
+bundle_impl!(A, B, C, D, E, F, G);
+bundle_impl!(A, B, C, D, E, F, G, H);
+bundle_impl!(A, B, C, D, E, F, G, H, I);
+bundle_impl!(A, B, C, D, E, F, G, H, I, J);
+bundle_impl!(A, B, C, D, E, F, G, H, I, J, K);
+bundle_impl!(A, B, C, D, E, F, G, H, I, J, K, L);
\ No newline at end of file
@@ -1,15 +1,23 @@
-use std::cell::{Ref, RefCell, RefMut};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::{HashMap, HashSet};
 
-use super::fetch::{EventReader, EventWriter};
+use super::fetch::{EventReader, AllEventReader, ConsumingReader, EventWriter};
 
 /// # Event trait
 /// Defines an Event that Systems can send and receive
 /// 
+/// `IMMEDIATE` makes Readers see Events sent within the same Tick instead of the previous one,  
+/// the Events are then cleared when the buffers swap. It is `false` by default
+/// 
+/// Note: Immediate Events are only seen by Readers that run *after* the Writer,  
+/// so make sure to order the Systems accordingly.  
+/// A single System also cannot both read and write the same Immediate Event
+/// 
 /// ## WARNING
 /// Make sure the Event ID does not collide with Events from other plugins
 pub trait Event: 'static{
     const ID: &'static str;
+    const IMMEDIATE: bool = false;
 }
 
 /// # Event trait Wrapper
@@ -25,43 +33,80 @@ impl<T: Event> EventWrapper for T{
     }
 }
 
+/// # Event Stamp
+/// Records when an Event was sent
+/// 
+/// `sequence` is shared by all Events in the World and increases with every sent Event,  
+/// `tick` is the number of buffer swaps that happened before the Event was sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventStamp{
+    pub sequence: u64,
+    pub tick: u64
+}
+
+/// An Event alongside its Stamp, as it's kept in the queues
+pub(crate) struct Stamped<E>{
+    pub(crate) stamp: EventStamp,
+    pub(crate) event: E
+}
+
 /// # Event Map
 /// A Double Hashmap Buffer queue of Events
 /// 
 /// Maintains a Registry of Events to prevent illegal overrides and reading/writing non-existent Events
 /// 
 /// Buffers switch at the end of every Tick, clearing the previously Read-Only buffer
+/// 
+/// Immediate Events are read straight from the Write buffer and are cleared instead of being switched
+/// 
+/// Every sent Event gets stamped with the next sequence number and the current tick
 pub(crate) struct EventBufferMap{
     registry: HashSet<&'static str>,
+    immediate: HashSet<&'static str>,
     read_buffer: HashMap<&'static str, RefCell<Box<dyn EventQueue>>>,
     write_buffer: HashMap<&'static str, RefCell<Box<dyn EventQueue>>>,
+    sequence: Cell<u64>,
+    tick: u64
 }
 impl EventBufferMap{
     /// Create a new, empty EventMap
     pub fn new() -> Self{
         Self{
             registry: HashSet::new(),
+            immediate: HashSet::new(),
             read_buffer: HashMap::new(),
             write_buffer: HashMap::new(),
+            sequence: Cell::new(0),
+            tick: 0
         }
     }
 
     /// Register an Event
     pub fn register<T: Event>(&mut self){
+        self.register_with_capacity::<T>(0);
+    }
+    /// Register an Event with both of it's queues preallocated to hold `capacity` Events
+    /// 
+    /// Queues are cleared without shrinking, so the capacity sticks around across Ticks
+    pub fn register_with_capacity<T: Event>(&mut self, capacity: usize){
         if self.registry.contains(T::ID){
             // Events CANNOT share IDs because Systems expect a specific type
             // This ain't OOP, we can't replace one struct with another and expect it to go the same
             panic!("ERROR: Conflicting Event IDs: {}", T::ID)
         }
         self.registry.insert(T::ID);
-        self.read_buffer.insert(T::ID, RefCell::new(Box::new(Vec::<T>::new())));
-        self.write_buffer.insert(T::ID, RefCell::new(Box::new(Vec::<T>::new())));
+        if T::IMMEDIATE{
+            self.immediate.insert(T::ID);
+        }
+        self.read_buffer.insert(T::ID, RefCell::new(Box::new(Vec::<Stamped<T>>::with_capacity(capacity))));
+        self.write_buffer.insert(T::ID, RefCell::new(Box::new(Vec::<Stamped<T>>::with_capacity(capacity))));
     }
     /// Deregister an Event
     /// 
     /// This also clears the respective Event's Queues from both buffers
     pub fn deregister<T: Event>(&mut self){
         self.registry.remove(T::ID);
+        self.immediate.remove(T::ID);
         // Remove those events from the Map as they're no longer valid
         self.read_buffer.remove(T::ID);
         self.write_buffer.remove(T::ID);
@@ -73,7 +118,12 @@ impl EventBufferMap{
         for queue in self.read_buffer.values_mut(){
             queue.borrow_mut().clear();
         }
+        // Immediate Events have already been read this Tick
+        for id in self.immediate.iter(){
+            self.write_buffer.get_mut(id).unwrap().get_mut().clear();
+        }
         std::mem::swap(&mut self.read_buffer, &mut self.write_buffer);
+        self.tick += 1;
     }
     /// Get a Reader for an Event
     /// 
@@ -85,7 +135,11 @@ impl EventBufferMap{
         }
 
         // We have checks for valid ID and a backup Queue, so we can safely unwrap
-        let queue = self.read_buffer.get(T::ID).unwrap();
+        let queue = if T::IMMEDIATE{
+            self.write_buffer.get(T::ID).unwrap()
+        }else{
+            self.read_buffer.get(T::ID).unwrap()
+        };
 
         EventReader(
             Ref::map(
@@ -93,6 +147,54 @@ impl EventBufferMap{
                 |x| x.downcast_ref::<T>())
             )
     }
+    /// Get a Reader for both queues of an Event
+    /// 
+    /// Borrows the Write queue too, so it can't coexist with a Writer of the same Event
+    /// 
+    /// Panics if the requested Event is not registered
+    pub fn get_all_reader<'a, T: Event + 'static>(&'a self) -> AllEventReader<'a, T>{
+        // Check if the Event is valid
+        if !self.registry.contains(T::ID){
+            panic!("ERROR: Attempted to fetch unregistered Event: {}", T::ID)
+        }
+
+        // We have checks for valid ID and a backup Queue, so we can safely unwrap
+        let read_queue = self.read_buffer.get(T::ID).unwrap();
+        let write_queue = self.write_buffer.get(T::ID).unwrap();
+
+        AllEventReader{
+            read: Ref::map(
+                read_queue.borrow(), 
+                |x| x.downcast_ref::<T>()),
+            write: Ref::map(
+                write_queue.borrow(), 
+                |x| x.downcast_ref::<T>())
+        }
+    }
+    /// Get a Consuming Reader for an Event
+    /// 
+    /// Borrows the same queue as the Reader, but mutably
+    /// 
+    /// Panics if the requested Event is not registered
+    pub fn get_consumer<'a, T: Event + 'static>(&'a self) -> ConsumingReader<'a, T>{
+        // Check if the Event is valid
+        if !self.registry.contains(T::ID){
+            panic!("ERROR: Attempted to fetch unregistered Event: {}", T::ID)
+        }
+
+        // Same as with the Reader
+        let queue = if T::IMMEDIATE{
+            self.write_buffer.get(T::ID).unwrap()
+        }else{
+            self.read_buffer.get(T::ID).unwrap()
+        };
+
+        ConsumingReader::new(
+            RefMut::map(
+                queue.borrow_mut(), 
+                |x| x.downcast_mut::<T>())
+            )
+    }
     /// Get a Writer for an Event
     /// 
     /// Panics if the requested Event is not registered
@@ -112,20 +214,24 @@ impl EventBufferMap{
                 |x| x.downcast_ref::<T>()),
             write: RefMut::map(
                 write_queue.borrow_mut(),
-                |x| x.downcast_mut::<T>())
+                |x| x.downcast_mut::<T>()),
+            sequence: &self.sequence,
+            tick: self.tick
         }
     }
-    /// Get a list of events currently in the Read Buffer
+    /// Get a list of events currently in the Read Buffer, plus Immediate Events in the Write Buffer
     /// 
     /// Called "active" as they're the ones being read in the current frame
+    /// 
+    /// The IDs are sorted, so Event Responders always run in the same order
     pub fn get_active_events(&self) -> Box<[&'static str]>{
-        // Bit of a mess, but it works
-        self.read_buffer.iter()
-            .map_while(
-                |(id, queue)|
-                Some(*id).filter(|_| queue.borrow().is_empty())
-            )
-            .collect()
+        let mut active: Vec<&'static str> = self.read_buffer.iter()
+            .chain(self.write_buffer.iter().filter(|(id, _)| self.immediate.contains(*id)))
+            .filter(|(_, queue)| !queue.borrow().is_empty())
+            .map(|(id, _)| *id)
+            .collect();
+        active.sort_unstable();
+        active.into_boxed_slice()
     }
     /// Get the Event registry 
     pub fn get_registry(&self) -> &HashSet<&'static str>{
@@ -143,7 +249,7 @@ trait EventQueue{
     /// Check if there are any events in this Queue
     fn is_empty(&self) -> bool;
 }
-impl<E: Event> EventQueue for Vec<E>{
+impl<E: Event> EventQueue for Vec<Stamped<E>>{
     fn clear(&mut self) {
         self.clear();
     }
@@ -153,12 +259,12 @@ impl<E: Event> EventQueue for Vec<E>{
 }
 impl dyn EventQueue{
     /// Downcast to a reference of an Event `T` queue
-    fn downcast_ref<T: Event>(&self) -> &Vec<T>{
-        unsafe{&*(self as *const dyn EventQueue as *const Vec<T>)}
+    fn downcast_ref<T: Event>(&self) -> &Vec<Stamped<T>>{
+        unsafe{&*(self as *const dyn EventQueue as *const Vec<Stamped<T>>)}
     }
     /// Downcast to a mutable reference of an Event `T` queue
-    fn downcast_mut<T: Event>(&mut self) -> &mut Vec<T>{
-        unsafe{&mut *(self as *mut dyn EventQueue as *mut Vec<T>)}
+    fn downcast_mut<T: Event>(&mut self) -> &mut Vec<Stamped<T>>{
+        unsafe{&mut *(self as *mut dyn EventQueue as *mut Vec<Stamped<T>>)}
     }
 }
 
@@ -172,4 +278,176 @@ impl dyn EventQueue{
 pub struct ExitApp(pub i32);
 impl Event for ExitApp{
     const ID: &'static str = "_APP_EXIT";
-}
\ No newline at end of file
+}
+
+/// **System Level Event**
+/// 
+/// Announces that an Entity's Component has been removed by a despawn
+/// 
+/// Sent after the Component's `on_remove` hook has ran
+pub struct ComponentRemoved{
+    /// ID of the despawned Entity
+    pub entity: usize,
+    /// ID of the removed Component
+    pub comp: &'static str
+}
+impl Event for ComponentRemoved{
+    const ID: &'static str = "_COMP_REMOVED";
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    struct idkfa(u32);
+    impl Event for idkfa{
+        const ID: &'static str = "idkfa";
+    }
+
+    struct iddqd(u32);
+    impl Event for iddqd{
+        const ID: &'static str = "iddqd";
+        const IMMEDIATE: bool = true;
+    }
+
+    #[test]
+    fn test_reader_writer(){
+        let mut events = EventBufferMap::new();
+        events.register::<idkfa>();
+
+        let mut writer = events.get_writer::<idkfa>();
+        writer.send(idkfa(5));
+        writer.send(idkfa(10));
+        assert!(writer.current_event_count() == 2);
+        assert!(writer.prev_event_count() == 0);
+        assert!(writer.current_iter().map(|event| event.0).eq([5, 10]));
+        drop(writer);
+
+        // Not visible until the buffers swap
+        assert!(events.get_reader::<idkfa>().event_count() == 0);
+
+        events.swap_buffers();
+
+        let reader = events.get_reader::<idkfa>();
+        assert!(reader.event_count() == 2);
+        assert!(reader.iter().map(|event| event.0).eq([5, 10]));
+        drop(reader);
+
+        let mut writer = events.get_writer::<idkfa>();
+        writer.send(idkfa(15));
+        assert!(writer.current_event_count() == 1);
+        assert!(writer.prev_iter().map(|event| event.0).eq([5, 10]));
+        drop(writer);
+
+        events.swap_buffers();
+
+        // The previous Tick's Events are gone
+        assert!(events.get_reader::<idkfa>().iter().map(|event| event.0).eq([15]));
+    }
+    #[test]
+    fn test_all_reader(){
+        let mut events = EventBufferMap::new();
+        events.register::<idkfa>();
+
+        events.get_writer::<idkfa>().send(idkfa(5));
+        events.swap_buffers();
+        events.get_writer::<idkfa>().send(idkfa(10));
+
+        let reader = events.get_all_reader::<idkfa>();
+        assert!(reader.event_count() == 2);
+        assert!(reader.read_all_iter().map(|event| event.0).eq([5, 10]));
+        drop(reader);
+
+        // The normal Reader only sees the previous frame
+        assert!(events.get_reader::<idkfa>().iter().map(|event| event.0).eq([5]));
+    }
+    #[test]
+    fn test_consumer(){
+        let mut events = EventBufferMap::new();
+        events.register::<idkfa>();
+
+        let mut writer = events.get_writer::<idkfa>();
+        for id in 0..4{
+            writer.send(idkfa(id));
+        }
+        drop(writer);
+        events.swap_buffers();
+
+        let mut consumer = events.get_consumer::<idkfa>();
+        assert!(consumer.consume().unwrap().0 == 0);
+        assert!(consumer.event_count() == 3);
+        assert!(consumer.consume_all().map(|event| event.0).eq([1, 2, 3]));
+        assert!(consumer.consume().is_none());
+        drop(consumer);
+
+        // Consumed for everyone else too
+        assert!(events.get_reader::<idkfa>().event_count() == 0);
+    }
+    #[test]
+    fn test_consumer_partial(){
+        let mut events = EventBufferMap::new();
+        events.register::<idkfa>();
+
+        let mut writer = events.get_writer::<idkfa>();
+        for id in 0..4{
+            writer.send(idkfa(id));
+        }
+        drop(writer);
+        events.swap_buffers();
+
+        let mut consumer = events.get_consumer::<idkfa>();
+        assert!(consumer.consume().unwrap().0 == 0);
+        assert!(consumer.consume().unwrap().0 == 1);
+        assert!(consumer.iter().map(|event| event.0).eq([2, 3]));
+        drop(consumer);
+
+        // The rest is left in order for the next Systems
+        assert!(events.get_reader::<idkfa>().iter().map(|event| event.0).eq([2, 3]));
+        assert!(events.get_consumer::<idkfa>().consume().unwrap().0 == 2);
+        assert!(events.get_reader::<idkfa>().iter().map(|event| event.0).eq([3]));
+    }
+    #[test]
+    fn test_immediate(){
+        let mut events = EventBufferMap::new();
+        events.register::<iddqd>();
+
+        events.get_writer::<iddqd>().send(iddqd(5));
+        assert!(events.get_reader::<iddqd>().iter().map(|event| event.0).eq([5]));
+
+        events.swap_buffers();
+
+        assert!(events.get_reader::<iddqd>().event_count() == 0);
+    }
+    #[test]
+    #[should_panic]
+    fn test_unregistered(){
+        let events = EventBufferMap::new();
+
+        // SHOULD PANIC
+        events.get_reader::<idkfa>();
+    }
+    #[test]
+    fn test_capacity(){
+        let mut events = EventBufferMap::new();
+        events.register_with_capacity::<idkfa>(256);
+
+        let capacity = |events: &EventBufferMap| [&events.read_buffer, &events.write_buffer]
+            .map(|buffer| buffer.get(idkfa::ID).unwrap().borrow().downcast_ref::<idkfa>().capacity());
+
+        assert!(capacity(&events).iter().all(|cap| *cap >= 256));
+
+        for tick in 0..4{
+            let mut writer = events.get_writer::<idkfa>();
+            for id in 0..200{
+                writer.send(idkfa(tick * 200 + id));
+            }
+            drop(writer);
+
+            events.swap_buffers();
+        }
+
+        // Both queues got cleared on the way, but kept their capacity
+        assert!(capacity(&events).iter().all(|cap| *cap >= 256));
+        assert!(events.get_reader::<idkfa>().event_count() == 200);
+    }
+}
@@ -1,10 +1,13 @@
 #![allow(type_alias_bounds)]
-use std::cell::{RefMut, Ref};
+use std::any::Any;
+use std::cell::{Cell, RefMut, Ref};
+use std::collections::{HashMap, VecDeque};
 
 use super::comp::Component;
-use super::events::Event;
+use super::events::{Event, EventStamp, Stamped};
 use super::resource::Resource;
-use super::commands::{Command, CommandWrapper};
+use super::commands::{Command, CommandWrapper, TicketedCommand, Ticketed, Ticket, Spawn, Insert, Despawn};
+use super::entity::{Bundle, Token};
 
 pub mod query;
 pub mod request;
@@ -15,33 +18,120 @@ pub type FetchMut<'a, C: Component> = RefMut<'a, C::STORAGE>;
 pub type FetchRes<'a, R: Resource> = Ref<'a, R>;
 pub type FetchResMut<'a, R: Resource> = RefMut<'a, R>;
 
+/// # Data Access
+/// Describes a single piece of World data that a Request, Query or Filter accesses
+/// 
+/// Each variant holds the ID of the accessed Component, Resource or Event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access{
+    Comp(&'static str),
+    CompMut(&'static str),
+    Res(&'static str),
+    ResMut(&'static str),
+    EventRead(&'static str),
+    EventWrite(&'static str),
+    /// Takes Events out of the read queue, so it conflicts with Readers of the same Event
+    EventConsume(&'static str),
+    Commands,
+    Triggers
+}
+
 /// # Event Reader
 /// Lets you read events that have been sent on the previous frame
-pub struct EventReader<'a, E: Event>(pub(crate) Ref<'a, Vec<E>>);
+pub struct EventReader<'a, E: Event>(pub(crate) Ref<'a, Vec<Stamped<E>>>);
 impl<E: Event> EventReader<'_, E>{
     /// Iterate over events sent on the previous frame
     pub fn iter(&self) -> impl Iterator<Item = &E>{
-        self.0.iter()
+        self.0.iter().map(|stamped| &stamped.event)
+    }
+    /// Iterate over events sent on the previous frame alongside their Stamps
+    /// 
+    /// Events are yielded by their sequence, which is the exact order they were sent in
+    pub fn read_ordered(&self) -> impl Iterator<Item = (EventStamp, &E)>{
+        // The queue only ever gets pushed to, so it's already in sequence order
+        self.0.iter().map(|stamped| (stamped.stamp, &stamped.event))
     }
     /// Get the number events that were sent on the previous frame
     pub fn event_count(&self) -> usize{
         self.0.len()
     }
 }
+/// # All Events Reader
+/// Lets you read every event that's currently alive, 
+/// both the ones sent on the previous frame and the ones sent on the current one so far
+/// 
+/// Meant for one-off diagnostics, Systems should generally stick to `EventReader`
+pub struct AllEventReader<'a, E: Event>{
+    pub(crate) read: Ref<'a, Vec<Stamped<E>>>,
+    pub(crate) write: Ref<'a, Vec<Stamped<E>>>
+}
+impl<E: Event> AllEventReader<'_, E>{
+    /// Iterate over events sent on the previous frame, followed by the ones sent on the current frame
+    pub fn read_all_iter(&self) -> impl Iterator<Item = &E>{
+        self.read.iter().chain(self.write.iter()).map(|stamped| &stamped.event)
+    }
+    /// Get the number of events across both frames
+    pub fn event_count(&self) -> usize{
+        self.read.len() + self.write.len()
+    }
+}
+/// # Consuming Event Reader
+/// Lets you take events that have been sent on the previous frame out of the queue
+/// 
+/// Consumed events are gone for every System that runs after this one,  
+/// so exactly one consumer processes each event.  
+/// This makes the order of Systems matter, make sure to set up Run Orders between consumers
+pub struct ConsumingReader<'a, E: Event>{
+    queue: RefMut<'a, Vec<Stamped<E>>>,
+    // The queue's events are moved here while consuming, so taking the oldest one is cheap
+    pending: VecDeque<Stamped<E>>
+}
+impl<'a, E: Event> ConsumingReader<'a, E>{
+    pub(crate) fn new(mut queue: RefMut<'a, Vec<Stamped<E>>>) -> Self{
+        let pending = std::mem::take(&mut *queue).into();
+        Self{ queue, pending }
+    }
+}
+impl<E: Event> ConsumingReader<'_, E>{
+    /// Take the oldest event out of the queue
+    pub fn consume(&mut self) -> Option<E>{
+        self.pending.pop_front().map(|stamped| stamped.event)
+    }
+    /// Take all remaining events out of the queue
+    pub fn consume_all(&mut self) -> impl Iterator<Item = E> + '_{
+        self.pending.drain(..).map(|stamped| stamped.event)
+    }
+    /// Iterate over the remaining events without consuming them
+    pub fn iter(&self) -> impl Iterator<Item = &E>{
+        self.pending.iter().map(|stamped| &stamped.event)
+    }
+    /// Get the number of events that are still left to consume
+    pub fn event_count(&self) -> usize{
+        self.pending.len()
+    }
+}
+impl<E: Event> Drop for ConsumingReader<'_, E>{
+    fn drop(&mut self) {
+        // Whatever wasn't consumed goes back for the Systems after this one
+        *self.queue = std::mem::take(&mut self.pending).into();
+    }
+}
 /// # Event Writer
 /// Lets you read events that have been sent on the previous frame, as well as send events for next frame
 pub struct EventWriter<'a, E: Event>{
-    pub(crate) read: Ref<'a, Vec<E>>,
-    pub(crate) write: RefMut<'a, Vec<E>>
+    pub(crate) read: Ref<'a, Vec<Stamped<E>>>,
+    pub(crate) write: RefMut<'a, Vec<Stamped<E>>>,
+    pub(crate) sequence: &'a Cell<u64>,
+    pub(crate) tick: u64
 }
 impl<E: Event> EventWriter<'_, E>{
     /// Iterate over events sent on the current frame
     pub fn current_iter(&self) -> impl Iterator<Item = &E>{
-        self.write.iter()
+        self.write.iter().map(|stamped| &stamped.event)
     }
     /// Iterate over events sent on the previous frame
     pub fn prev_iter(&self) -> impl Iterator<Item = &E>{
-        self.read.iter()
+        self.read.iter().map(|stamped| &stamped.event)
     }
     /// Get the number of events that are present on the current frame
     pub fn current_event_count(&self) -> usize{
@@ -53,31 +143,180 @@ impl<E: Event> EventWriter<'_, E>{
     }
     // The only function exclusive to Writer
     /// Send an Event
+    /// 
+    /// The Event gets stamped with the next sequence number and the current tick
     pub fn send(&mut self, event: E){
-        self.write.push(event);
+        let sequence = self.sequence.get();
+        self.sequence.set(sequence + 1);
+
+        self.write.push(Stamped{
+            stamp: EventStamp{ sequence, tick: self.tick },
+            event
+        });
     }
 }
 
-pub struct CommandWriter<'a>(pub(crate) RefMut<'a, Vec<Box<dyn CommandWrapper>>>);
-impl CommandWriter<'_>{
+/// # Command Writer
+/// Lets you queue Commands to be executed on the World
+/// 
+/// Commands are applied at the end of the *same* Logic Tick they were sent in,  
+/// after Logic Systems, Singlefires and Event Responders, right before the Event buffers swap.  
+/// Postprocessors and Tick Hooks of that Tick already see their effects
+pub struct CommandWriter<'a>{
+    pub(crate) queue: RefMut<'a, Vec<Box<dyn CommandWrapper>>>,
+    pub(crate) tickets: &'a Cell<u64>
+}
+impl<'a> CommandWriter<'a>{
     /// Get the number of Commands that are currently in the queue
     pub fn command_count(&self) -> usize{
-        self.0.len()
+        self.queue.len()
     }
     /// Send a Command
     pub fn send<C: Command>(&mut self, command: C){
-        self.0.push(Box::new(command));
+        self.queue.push(Box::new(command));
+    }
+    /// Send a Command that produces a result
+    /// 
+    /// Once the Command is applied, redeem the returned Ticket via `CommandResults` on a later Tick,  
+    /// or via `World::take_command_result` from outside of Systems
+    pub fn send_with_ticket<C: TicketedCommand>(&mut self, command: C) -> Ticket<C::Output>{
+        let ticket = self.tickets.get();
+        self.tickets.set(ticket + 1);
+
+        self.send(Ticketed{ ticket, command });
+        Ticket::new(ticket)
+    }
+    /// Queue spawning a new Entity with the given Bundle
+    pub fn spawn<B: Bundle + 'static>(&mut self, bundle: B){
+        self.send(Spawn(Some(bundle)));
     }
+    /// Queue spawning a new Entity with the given Bundle, the Ticket redeems the Entity's ID
+    pub fn spawn_with_ticket<B: Bundle + 'static>(&mut self, bundle: B) -> Ticket<usize>{
+        self.send_with_ticket(Spawn(Some(bundle)))
+    }
+    /// Queue Commands for an existing Entity
+    /// 
+    /// The Token is checked once the Commands are applied, Commands for an Entity that's gone by then do nothing
+    pub fn entity<'w>(&'w mut self, token: &Token) -> EntityCommands<'w, 'a>{
+        EntityCommands{
+            writer: self,
+            entity: *token
+        }
+    }
+}
+/// Results of Ticketed Commands, stored per Ticket alongside the Tick they were stored on
+pub(crate) type CommandResultMap = HashMap<u64, (u64, Box<dyn Any>)>;
+
+/// # Command Result Reader
+/// Lets Systems redeem the Tickets of the Ticketed Commands they sent
+/// 
+/// Results become available once the Commands are applied at the end of the Tick,  
+/// and are dropped if they're not taken within a few seconds
+pub struct ResultReader<'a>(pub(crate) RefMut<'a, CommandResultMap>);
+impl ResultReader<'_>{
+    /// Take the result of a Ticketed Command
+    /// 
+    /// Returns None if the Command hasn't been applied yet or the result is gone
+    pub fn take<T: 'static>(&mut self, ticket: Ticket<T>) -> Option<T>{
+        take_result(&mut self.0, ticket)
+    }
+    /// Get the number of results waiting to be taken
+    pub fn result_count(&self) -> usize{
+        self.0.len()
+    }
+}
+
+/// Take the result of a Ticketed Command out of the results
+pub(crate) fn take_result<T: 'static>(results: &mut CommandResultMap, ticket: Ticket<T>) -> Option<T>{
+    let (_, result) = results.remove(&ticket.id())?;
+    // The Ticket is typed, so the downcast can't fail
+    Some(*result.downcast::<T>().unwrap())
+}
+
+/// # Entity Commands
+/// Queues Commands for a single Entity, get it from `CommandWriter::entity`
+pub struct EntityCommands<'w, 'a>{
+    writer: &'w mut CommandWriter<'a>,
+    entity: Token
+}
+impl EntityCommands<'_, '_>{
+    /// Queue inserting a Component to the Entity
+    pub fn insert<C: Component>(&mut self, comp: C) -> &mut Self{
+        self.writer.send(Insert{ entity: self.entity, comp: Some(comp) });
+        self
+    }
+    /// Queue despawning the Entity
+    pub fn despawn(self){
+        self.writer.send(Despawn(self.entity));
+    }
+}
+/// # Trigger Writer
+/// Lets you fire Singlefire Systems
+/// 
+/// Triggers sent by Logic Systems fire in the same Tick, right after the Logic Systems.  
+/// Ones sent by Singlefires, Event Responders, Preprocessors and Postprocessors fire on the next Logic Tick,  
+/// use `send_immediate` to fire them within the same Tick instead
+pub struct TriggerWriter<'a>{
+    pub(crate) queue: RefMut<'a, Vec<&'static str>>,
+    pub(crate) immediate: RefMut<'a, Vec<&'static str>>,
+    pub(crate) payloads: RefMut<'a, TriggerPayloadMap>
 }
-pub struct TriggerWriter<'a>(pub(crate) RefMut<'a, Vec<&'static str>>);
 impl TriggerWriter<'_>{
     /// Get the numebr of Triggers that are currently in the queue
     pub fn trigger_count(&self) -> usize{
-        self.0.len()
+        self.queue.len() + self.immediate.len()
     }
     /// Send a Trigger
     pub fn send(&mut self, trigger: &'static str){
-        self.0.push(trigger);
+        self.queue.push(trigger);
+    }
+    /// Send a Trigger that fires within the current Logic Tick
+    /// 
+    /// Sent from Logic Systems, Singlefires or Event Responders, the Singlefire runs right after  
+    /// the Singlefire phase or the Event Responder phase respectively, which allows chaining Singlefires.  
+    /// Sent from Preprocessors and Postprocessors, it fires on the next Logic Tick same as `send`
+    pub fn send_immediate(&mut self, trigger: &'static str){
+        self.immediate.push(trigger);
+    }
+    /// Send a Trigger along with a payload for the Singlefire to read
+    /// 
+    /// Payloads of the same Trigger are read in the order they were sent
+    pub fn send_with<T: 'static>(&mut self, trigger: &'static str, payload: T){
+        self.queue.push(trigger);
+        self.payloads.entry(trigger).or_default().push_back(Box::new(payload));
+    }
+}
+
+/// Payloads of Triggers, queued per Trigger ID
+pub(crate) type TriggerPayloadMap = HashMap<&'static str, VecDeque<Box<dyn Any>>>;
+
+/// # Trigger Payload Reader
+/// Lets Singlefires take the payloads their Triggers were sent with
+/// 
+/// Payloads only live for the Tick their Trigger fires in
+pub struct PayloadReader<'a>(pub(crate) RefMut<'a, TriggerPayloadMap>);
+impl PayloadReader<'_>{
+    /// Take the oldest payload of the given Trigger
+    /// 
+    /// Returns None if there are no payloads left or the payload is not a `T`
+    pub fn take<T: 'static>(&mut self, trigger: &str) -> Option<T>{
+        take_payload(&mut self.0, trigger)
+    }
+    /// Get the number of payloads left for the given Trigger
+    pub fn payload_count(&self, trigger: &str) -> usize{
+        self.0.get(trigger).map_or(0, |payloads| payloads.len())
+    }
+}
+
+/// Take the oldest payload of a Trigger, leaving it in place if it's of a different type
+pub(crate) fn take_payload<T: 'static>(payloads: &mut TriggerPayloadMap, trigger: &str) -> Option<T>{
+    let queue = payloads.get_mut(trigger)?;
+    match queue.pop_front()?.downcast::<T>(){
+        Ok(payload) => Some(*payload),
+        Err(payload) => {
+            queue.push_front(payload);
+            None
+        }
     }
 }
 
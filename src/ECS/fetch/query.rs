@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, ops::{Deref, DerefMut}};
+use std::{cell::{Ref, RefMut}, collections::BTreeMap, marker::PhantomData, ops::{Deref, DerefMut}};
 
 use crate::ECS;
 use ECS::entity;
@@ -6,7 +6,7 @@ use ECS::storage::Storage;
 use ECS::entity::Entity;
 use ECS::world::World;
 use ECS::comp::Component;
-use super::{Fetch, FetchMut};
+use super::{Access, Fetch, FetchMut};
 
 /// # Query fetch trait
 /// Required for `Query` to know what to fetch from the World
@@ -21,6 +21,8 @@ use super::{Fetch, FetchMut};
 /// 
 /// `MutAccItem` is what gets returned when getting data mutably.  
 /// Read-only Components simply return their `AccItem` when getting mutably, such as `&Component`
+/// 
+/// `describe` lists the Components the data accesses, it describes nothing by default
 pub trait QueryData{
     type Item<'b>;
     type AccItem<'b>;
@@ -32,6 +34,10 @@ pub trait QueryData{
     fn get<'a, 'qref: 'a, 'query: 'qref>(fetched: &'qref Self::Item<'query>, id: &usize) -> Option<Self::AccItem<'a>>;
     /// Access given Entity's data mutably
     fn get_mut<'a, 'qref: 'a, 'query: 'qref>(fetched: &'qref mut Self::Item<'query>, id: &usize) -> Option<Self::MutAccItem<'a>>;
+    /// Describe the Components this data accesses
+    fn describe() -> Vec<Access>{
+        Vec::new()
+    }
 }
 
 /// # Query Filter trait
@@ -47,12 +53,18 @@ pub trait QueryData{
 /// only to read if the requirements are met
 /// 
 /// `Item` is the data the filter needs to filter out the Entity
+/// 
+/// `describe` lists the Components the filter reads, it describes nothing by default
 pub trait QueryFilter{
     type Item<'b>;
     /// Fetch the needed data from the World
     fn fetch<'a>(world: &'a World) -> Self::Item<'a>;
     /// Check if the given entity passes this filter
     fn filter<'qref, 'query: 'qref>(fetched: &'qref Self::Item<'query>, id: &usize) -> bool;
+    /// Describe the Components this filter reads
+    fn describe() -> Vec<Access>{
+        Vec::new()
+    }
 }
 
 /// # World Query
@@ -71,7 +83,7 @@ pub trait QueryFilter{
 /// 
 /// Query automatically validates Tokens in Getter functions, they can also be  
 /// manually validated via `validate_token`
-pub struct WorldQuery<'world, D: QueryData, F: QueryFilter>{
+pub struct WorldQuery<'world, D: QueryData, F: QueryFilter = ()>{
     entities: &'world BTreeMap<usize, Entity>,
     filter_data: F::Item<'world>,
     data: D::Item<'world>
@@ -114,6 +126,13 @@ impl<'world, D: QueryData, F: QueryFilter> WorldQuery<'world, D, F>{
         }
 
     }
+    /// Get sets of Components for several entities at once
+    /// 
+    /// All lookups go through the Storages this Query already holds,  
+    /// each slot is `None` if its entity doesn't exist or lacks a requested Component
+    pub fn get_array<'a, 'qref: 'a, const N: usize>(&'qref self, ids: [usize; N]) -> [Option<D::AccItem<'a>>; N]{
+        ids.map(|id| self.get(&id))
+    }
 
     /// Get a mutable set of Components for a given entity
     /// 
@@ -148,6 +167,8 @@ impl<'world, D: QueryData, F: QueryFilter> WorldQuery<'world, D, F>{
     /// Iterate over all matching entities immutably  
     /// 
     /// Entities that don't have at least one matching Component will not be iterated over
+    /// 
+    /// Entities are yielded in ascending ID order, regardless of the Storages used
     pub fn iter<'a, 'query: 'a>(&'query self) -> Iter<'world, 'a, D, F>{
         Iter{
             data: &self.data,
@@ -158,6 +179,8 @@ impl<'world, D: QueryData, F: QueryFilter> WorldQuery<'world, D, F>{
     /// Iterate over all matching entities mutably  
     /// 
     /// Entities that don't have at least one matching Component will not be iterated over
+    /// 
+    /// Entities are yielded in ascending ID order, regardless of the Storages used
     pub fn iter_mut<'iter, 'query: 'iter>(&'query mut self) -> IterMut<'iter, 'world, D, F>{
         IterMut{
             data: &mut self.data,
@@ -166,6 +189,35 @@ impl<'world, D: QueryData, F: QueryFilter> WorldQuery<'world, D, F>{
         }
     }
 
+    /// Iterate over all matching entities immutably, along with their IDs
+    /// 
+    /// Same as `iter`, but pairs every set of Components with the ID of it's entity
+    pub fn iter_with_id<'a, 'query: 'a>(&'query self) -> impl Iterator<Item = (usize, D::AccItem<'a>)>{
+        let mut iter = self.iter();
+        std::iter::from_fn(move || iter.next_with_id())
+    }
+    /// Iterate over all matching entities mutably, along with their IDs
+    /// 
+    /// Same as `iter_mut`, but pairs every set of Components with the ID of it's entity
+    pub fn iter_mut_with_id<'iter, 'query: 'iter>(&'query mut self) -> impl Iterator<Item = (usize, D::MutAccItem<'iter>)>{
+        let mut iter = self.iter_mut();
+        std::iter::from_fn(move || iter.next_with_id())
+    }
+
+    /// Check if any matching entity satisfies `pred`
+    /// 
+    /// Stops at the first entity that does
+    pub fn any<'a, 'query: 'a>(&'query self, pred: impl FnMut(D::AccItem<'a>) -> bool) -> bool{
+        self.iter().any(pred)
+    }
+    /// Check if every matching entity satisfies `pred`
+    /// 
+    /// Stops at the first entity that doesn't.  
+    /// Returns `true` if there are no matching entities
+    pub fn all<'a, 'query: 'a>(&'query self, pred: impl FnMut(D::AccItem<'a>) -> bool) -> bool{
+        self.iter().all(pred)
+    }
+
     /// Validate an Entity Token  
     /// 
     /// Updates Token's `valid` flag and returns boolean whether it's still valid or not
@@ -178,6 +230,59 @@ impl<'world, D: QueryData, F: QueryFilter> WorldQuery<'world, D, F>{
         self.entities.get(&token.id()).is_some_and(|entity| token.validate(entity))
     }
 }
+#[cfg(feature = "rayon")]
+impl<'world, D: QueryData, F: QueryFilter> WorldQuery<'world, D, F>{
+    /// Run `f` on all matching entities immutably, in parallel
+    /// 
+    /// The matching entities are gathered first, then split across threads
+    pub fn par_for_each<'a, 'query: 'a>(&'query self, f: impl Fn(D::AccItem<'a>) + Sync + Send) where D::AccItem<'a>: Send{
+        use rayon::prelude::*;
+
+        self.iter().collect::<Vec<_>>().into_par_iter().for_each(f)
+    }
+    /// Run `f` on all matching entities mutably, in parallel
+    /// 
+    /// The matching entities are gathered first, then split across threads.  
+    /// Each Entity is yielded by the iterator only once, so no two threads get the same Components
+    pub fn par_for_each_mut<'a, 'query: 'a>(&'query mut self, f: impl Fn(D::MutAccItem<'a>) + Sync + Send) where D::MutAccItem<'a>: Send{
+        use rayon::prelude::*;
+
+        self.iter_mut().collect::<Vec<_>>().into_par_iter().for_each(f)
+    }
+}
+impl<'world, C: Component, F: QueryFilter> WorldQuery<'world, &C, F>{
+    /// Get the given Entity's Component as a `Ref` guard
+    /// 
+    /// Unlike `get`, the guard isn't tied to the Query's borrow, so it can be stored  
+    /// in a local or returned out of the function after the Query is gone.  
+    /// The Storage stays borrowed for as long as the guard lives
+    /// 
+    /// Filters apply as usual
+    pub fn fetch_ref(&self, id: &usize) -> Option<Ref<'world, C>>{
+        if !self.entities.contains_key(id) || !F::filter(&self.filter_data, id){
+            return None
+        }
+        Ref::filter_map(
+            Ref::clone(&self.data), 
+            |storage| storage.get(id)).ok()
+    }
+}
+impl<'world, C: Component, F: QueryFilter> WorldQuery<'world, &mut C, F>{
+    /// Turn the Query into a `RefMut` guard of the given Entity's Component
+    /// 
+    /// A mutable guard can't be shared, so this consumes the Query.  
+    /// The guard can then be stored in a local or returned out of the function
+    /// 
+    /// Filters apply as usual
+    pub fn into_ref_mut(self, id: &usize) -> Option<RefMut<'world, C>>{
+        if !self.entities.contains_key(id) || !F::filter(&self.filter_data, id){
+            return None
+        }
+        RefMut::filter_map(
+            self.data, 
+            |storage| storage.get_mut(id)).ok()
+    }
+}
 impl<'world, D:QueryData, F: QueryFilter> Deref for WorldQuery<'world, D, F>{
     type Target = D::Item<'world>;
 
@@ -203,20 +308,27 @@ pub struct Iter<'query: 'qref, 'qref, D: QueryData, F: QueryFilter>{
     filters: &'qref F::Item<'query>,
     ent_iter: Keys<'query, usize, Entity>
 }
-impl<'query: 'qref, 'qref, D: QueryData, F: QueryFilter> Iterator for Iter<'query, 'qref, D, F>{
-    type Item = D::AccItem<'qref>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        // return Some(true);
+impl<'query: 'qref, 'qref, D: QueryData, F: QueryFilter> Iter<'query, 'qref, D, F>{
+    /// Get the next matching entity's data along with it's ID
+    fn next_with_id(&mut self) -> Option<(usize, D::AccItem<'qref>)>{
         loop{
             let index = self.ent_iter.next()?;
-                
-            if F::filter(self.filters, index){
-                return D::get(self.data, index)
+
+            // Entities missing a required Component are skipped, not the end of the iteration
+            if F::filter(self.filters, index)
+            && let Some(item) = D::get(self.data, index){
+                return Some((*index, item))
             }
         }
     }
 }
+impl<'query: 'qref, 'qref, D: QueryData, F: QueryFilter> Iterator for Iter<'query, 'qref, D, F>{
+    type Item = D::AccItem<'qref>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_id().map(|(_, item)| item)
+    }
+}
 
 /// # Mutable Query Iterator
 /// Iterates over entities that have all matching Components of `D`ata mutably
@@ -225,18 +337,20 @@ pub struct IterMut<'qref, 'query: 'qref, D: QueryData, F: QueryFilter>{
     filters: &'qref F::Item<'query>,
     ent_iter: Keys<'query, usize, Entity>
 }
-impl<'qref, 'query: 'qref, D: QueryData, F: QueryFilter> Iterator for IterMut<'qref, 'query, D, F>{
-    type Item = D::MutAccItem<'qref>;
-
-    fn next(&mut self) -> Option<Self::Item> {
+impl<'qref, 'query: 'qref, D: QueryData, F: QueryFilter> IterMut<'qref, 'query, D, F>{
+    /// Get the next matching entity's data along with it's ID
+    fn next_with_id(&mut self) -> Option<(usize, D::MutAccItem<'qref>)>{
         loop{
             let index = self.ent_iter.next()?;
             // let index = &0;
 
             // return Some(F::filter(self.filters, index));
 
-            if F::filter(self.filters, index){
-                return 
+            if !F::filter(self.filters, index){
+                continue
+            }
+            // Same as above, skip Entities missing a required Component
+            if let Some(item) = 
                     D::get_mut(
                         // SAFETY: I have no goddamn pecking idea
                         // But this is what 
@@ -258,11 +372,19 @@ impl<'qref, 'query: 'qref, D: QueryData, F: QueryFilter> Iterator for IterMut<'q
                         // Unless I redo the engine 4th time in a row
                         unsafe{&mut *(self.data as *mut D::Item<'query>)}, 
                         index
-                    )
+                    ){
+                return Some((*index, item))
             }
         }
     }
 }
+impl<'qref, 'query: 'qref, D: QueryData, F: QueryFilter> Iterator for IterMut<'qref, 'query, D, F>{
+    type Item = D::MutAccItem<'qref>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_id().map(|(_, item)| item)
+    }
+}
 
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -284,7 +406,10 @@ impl<C:Component> QueryData for &C{
     }
     fn get_mut<'a, 'qref: 'a, 'query: 'qref>(fetched: &'qref mut Self::Item<'query>, id: &usize) -> Option<Self::MutAccItem<'a>> {
         fetched.get(id)
-    }    
+    }
+    fn describe() -> Vec<Access> {
+        vec![Access::Comp(C::ID)]
+    }
 }
 impl<C: Component> QueryData for &mut C{
     type Item<'b> = FetchMut<'b, C>;
@@ -302,6 +427,9 @@ impl<C: Component> QueryData for &mut C{
     fn get_mut<'a, 'qref: 'a, 'query: 'qref>(fetched: &'qref mut Self::Item<'query>, id: &usize) -> Option<Self::MutAccItem<'a>> {
         fetched.get_mut(id)
     }
+    fn describe() -> Vec<Access> {
+        vec![Access::CompMut(C::ID)]
+    }
 }
 
 impl<C: Component> QueryData for Option<&C>{
@@ -326,6 +454,9 @@ impl<C: Component> QueryData for Option<&C>{
     fn get_mut<'a, 'qref: 'a, 'query: 'qref>(fetched: &'qref mut Self::Item<'query>, id: &usize) -> Option<Self::MutAccItem<'a>> {
         Some(fetched.get(id))
     }
+    fn describe() -> Vec<Access> {
+        vec![Access::Comp(C::ID)]
+    }
 }
 impl<C: Component> QueryData for Option<&mut C>{
     type Item<'b> = FetchMut<'b, C>;
@@ -342,6 +473,100 @@ impl<C: Component> QueryData for Option<&mut C>{
     fn get_mut<'a, 'qref: 'a, 'query: 'qref>(fetched: &'qref mut Self::Item<'query>, id: &usize) -> Option<Self::MutAccItem<'a>> {
         Some(fetched.get_mut(id))
     }
+    fn describe() -> Vec<Access> {
+        vec![Access::CompMut(C::ID)]
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Filters
+///////////////////////////////////////////////////////////////////////////////
+
+/// # With Filter
+/// Passes Entities that have the `C` Component
+pub struct With<C: Component>(PhantomData<C>);
+impl<C: Component> QueryFilter for With<C>{
+    type Item<'b> = Fetch<'b, C>;
+
+    fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
+        world.fetch::<C>()
+    }
+
+    fn filter<'qref, 'query: 'qref>(fetched: &'qref Self::Item<'query>, id: &usize) -> bool {
+        fetched.get(id).is_some()
+    }
+    fn describe() -> Vec<Access> {
+        vec![Access::Comp(C::ID)]
+    }
+}
+
+/// # Without Filter
+/// Passes Entities that do not have the `C` Component
+/// 
+/// The `C` storage is only borrowed immutably, so it can be used alongside  
+/// mutable requests of *other* Components.  
+/// Requesting `&mut C` in the same Query is a conflict and will panic on fetch
+pub struct Without<C: Component>(PhantomData<C>);
+impl<C: Component> QueryFilter for Without<C>{
+    type Item<'b> = Fetch<'b, C>;
+
+    fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
+        world.fetch::<C>()
+    }
+
+    fn filter<'qref, 'query: 'qref>(fetched: &'qref Self::Item<'query>, id: &usize) -> bool {
+        fetched.get(id).is_none()
+    }
+    fn describe() -> Vec<Access> {
+        vec![Access::Comp(C::ID)]
+    }
+}
+
+/// # Component Predicate trait
+/// Defines a check on a `C` Component's value, used by the `Where` filter
+/// 
+/// Closures can't be used as generic parameters,  
+/// so the predicate lives on a marker type instead:
+/// ```
+/// # use manufacture_engine::prelude::*;
+/// struct Health(u32);
+/// impl Component for Health{
+///     type STORAGE = DefaultStorage<Self>;
+///     const ID: &'static str = "Health";
+/// }
+/// 
+/// struct IsAlive;
+/// impl Predicate<Health> for IsAlive{
+///     fn test(comp: &Health) -> bool{
+///         comp.0 > 0
+///     }
+/// }
+/// 
+/// type Data<'a> = Query<&'a mut Health, Where<Health, IsAlive>>;
+/// ```
+pub trait Predicate<C: Component>: 'static{
+    /// Check if the Component passes the predicate
+    fn test(comp: &C) -> bool;
+}
+
+/// # Where Filter
+/// Passes Entities that have the `C` Component and whose `C` passes the `P` predicate
+/// 
+/// Same as `Without`, the `C` storage is only borrowed immutably
+pub struct Where<C: Component, P: Predicate<C>>(PhantomData<(C, P)>);
+impl<C: Component, P: Predicate<C>> QueryFilter for Where<C, P>{
+    type Item<'b> = Fetch<'b, C>;
+
+    fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
+        world.fetch::<C>()
+    }
+
+    fn filter<'qref, 'query: 'qref>(fetched: &'qref Self::Item<'query>, id: &usize) -> bool {
+        fetched.get(id).is_some_and(P::test)
+    }
+    fn describe() -> Vec<Access> {
+        vec![Access::Comp(C::ID)]
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -353,9 +578,7 @@ impl QueryData for (){
     type AccItem<'b> = ();
     type MutAccItem<'b> = ();
 
-    fn fetch<'a>(_world: &'a World) -> Self::Item<'a> {
-        ()
-    }
+    fn fetch<'a>(_world: &'a World) -> Self::Item<'a> {}
     fn get<'a, 'qref: 'a, 'query: 'qref>(_fetched: &'qref Self::Item<'query>, _id: &usize) -> Option<Self::AccItem<'a>> {
         Some(())
     }
@@ -367,9 +590,7 @@ impl QueryData for (){
 impl QueryFilter for (){
     type Item<'b> = ();
 
-    fn fetch<'a>(_world: &'a World) -> Self::Item<'a> {
-        ()
-    }
+    fn fetch<'a>(_world: &'a World) -> Self::Item<'a> {}
     fn filter<'qref, 'query: 'qref>(_fetched: &'qref Self::Item<'query>, _index: &usize) -> bool {
         true
     }
@@ -398,6 +619,11 @@ macro_rules! query_impl {
                     ($($x::get_mut($x, Index)?), *)
                 )
             }
+            fn describe() -> Vec<Access> {
+                let mut access = Vec::new();
+                $(access.extend($x::describe());)*
+                access
+            }
         }
     }
 }
@@ -415,6 +641,11 @@ macro_rules! filter_impl {
             fn filter<'qref, 'query: 'qref>(($($x), *): &'qref Self::Item<'query>, Index: &usize) -> bool {
                 $($x::filter($x, Index)) && *
             }
+            fn describe() -> Vec<Access> {
+                let mut access = Vec::new();
+                $(access.extend($x::describe());)*
+                access
+            }
         }
     }
 }
@@ -486,6 +717,40 @@ mod tests{
             assert!(query.1.get_mut(&0).is_some());
         }
         #[test]
+        fn test_fetch_ref(){
+            fn find(world: &World, id: usize) -> Option<Ref<'_, idkfa>>{
+                let query: WorldQuery<&idkfa, Without<iddqd>> = WorldQuery::fetch(world);
+                query.fetch_ref(&id)
+            }
+            fn find_mut(world: &World, id: usize) -> Option<RefMut<'_, idkfa>>{
+                let query: WorldQuery<&mut idkfa> = WorldQuery::fetch(world);
+                query.into_ref_mut(&id)
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(idkfa(5)).finish();
+            world.spawn().with(idkfa(10)).with(iddqd(10)).finish();
+
+            // The Query is gone by now, the guards outlive it
+            let first = find(&world, 0).unwrap();
+            let again = find(&world, 0).unwrap();
+            assert!(first.0 == 5 && again.0 == 5);
+            drop((first, again));
+
+            // Filtered out and missing
+            assert!(find(&world, 1).is_none());
+            assert!(find(&world, 2).is_none());
+
+            let mut comp = find_mut(&world, 1).unwrap();
+            comp.0 = 15;
+            drop(comp);
+            assert!(world.fetch::<idkfa>().get(&1).unwrap().0 == 15);
+            assert!(find_mut(&world, 2).is_none());
+        }
+        #[test]
         fn test_iter(){
             let mut world = World::new();
             world.register_comp::<idkfa>();
@@ -509,12 +774,31 @@ mod tests{
                 }
             };
         }
+        #[test]
+        fn test_get_array(){
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(idkfa(0)).with(iddqd(0)).finish();
+            world.spawn().with(idkfa(1)).finish();
+            world.spawn().with(idkfa(2)).with(iddqd(2)).finish();
+
+            let query: WorldQuery<'_, (&idkfa, &iddqd), ()> = WorldQuery::fetch(&world);
+
+            let [first, second, third] = query.get_array([2, 1, 0]);
+            assert!(first.is_some_and(|(kfa, dqd)| kfa.0 == 2 && dqd.0 == 2));
+            // Missing `iddqd`
+            assert!(second.is_none());
+            assert!(third.is_some_and(|(kfa, _)| kfa.0 == 0));
+
+            // Nonexistent entity
+            assert!(query.get_array([7])[0].is_none());
+        }
     }
-    mod test_filter{
-        #![allow(unused)]
+    mod test_iter{
         use super::*;
         use crate::ECS::storage::test::TestStorage;
-        use std::marker::PhantomData;
 
         struct idkfa(u8);
         struct iddqd(u8);
@@ -529,29 +813,127 @@ mod tests{
             const ID: &'static str = "iddqd";
         }
 
-        struct With<C: Component>(PhantomData<C>);
-        struct Without<C: Component>(PhantomData<C>);
-        impl<C: Component> QueryFilter for With<C>{
-            type Item<'b> = Fetch<'b, C>;
-        
-            fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
-                world.fetch::<C>()
-            }
-        
-            fn filter<'qref, 'query: 'qref>(fetched: &'qref Self::Item<'query>, id: &usize) -> bool {
-                fetched.get(id).is_some()
+        fn setup() -> World{
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            // Partial Entities come first, so they'd cut the iteration short
+            world.spawn().with(idkfa(0)).finish();
+            world.spawn().finish();
+            world.spawn().with(iddqd(2)).finish();
+            world.spawn().with(idkfa(3)).with(iddqd(3)).finish();
+            world.spawn().with(idkfa(4)).finish();
+            world.spawn().with(idkfa(5)).with(iddqd(5)).finish();
+
+            world
+        }
+
+        #[test]
+        fn test_required(){
+            let world = setup();
+
+            let mut query: WorldQuery<(&idkfa, &mut iddqd)> = WorldQuery::fetch(&world);
+
+            assert!(query.iter().map(|(kfa, _)| kfa.0).collect::<Vec<_>>() == [3, 5]);
+            assert!(query.iter_mut().map(|(kfa, _)| kfa.0).collect::<Vec<_>>() == [3, 5]);
+        }
+        #[test]
+        fn test_optional(){
+            let world = setup();
+
+            let mut query: WorldQuery<(&idkfa, Option<&mut iddqd>)> = WorldQuery::fetch(&world);
+
+            assert!(query.iter().map(|(kfa, dqd)| (kfa.0, dqd.is_some())).collect::<Vec<_>>() 
+                == [(0, false), (3, true), (4, false), (5, true)]);
+            assert!(query.iter_mut().count() == 4);
+        }
+        #[test]
+        fn test_any(){
+            let world = setup();
+
+            let query: WorldQuery<(&idkfa, &iddqd)> = WorldQuery::fetch(&world);
+
+            // Stops on Entity 3, Entity 5 is never checked
+            let mut checked = Vec::new();
+            assert!(query.any(|(kfa, _)| {
+                checked.push(kfa.0);
+                kfa.0 == 3
+            }));
+            assert!(checked == [3]);
+
+            assert!(!query.any(|(kfa, _)| kfa.0 == 4));
+        }
+        #[test]
+        fn test_all(){
+            let world = setup();
+
+            let query: WorldQuery<&idkfa> = WorldQuery::fetch(&world);
+
+            // Fails on Entity 3, the rest is never checked
+            let mut checked = Vec::new();
+            assert!(!query.all(|kfa| {
+                checked.push(kfa.0);
+                kfa.0 < 3
+            }));
+            assert!(checked == [0, 3]);
+
+            assert!(query.all(|kfa| kfa.0 <= 5));
+
+            // Nothing matches, so everything matches
+            let query: WorldQuery<&idkfa, Without<idkfa>> = WorldQuery::fetch(&world);
+            assert!(query.all(|_| false));
+        }
+        #[test]
+        fn test_with_id(){
+            let world = setup();
+
+            let mut query: WorldQuery<(&idkfa, Option<&mut iddqd>)> = WorldQuery::fetch(&world);
+
+            // The Components were spawned with their entity's ID
+            assert!(query.iter_with_id().all(|(id, (kfa, _))| id == kfa.0 as usize));
+            assert!(query.iter_with_id().map(|(id, _)| id).collect::<Vec<_>>() == [0, 3, 4, 5]);
+
+            for (id, (_, dqd)) in query.iter_mut_with_id(){
+                if let Some(dqd) = dqd{
+                    dqd.0 = id as u8 * 10;
+                }
             }
+            drop(query);
+
+            assert!(world.fetch::<iddqd>().get(&3).unwrap().0 == 30);
+            assert!(world.fetch::<iddqd>().get(&5).unwrap().0 == 50);
+        }
+        #[test]
+        fn test_no_components(){
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            // Present in the Entity map, absent from every Storage
+            world.spawn().finish();
+            world.spawn().finish();
+
+            let mut query: WorldQuery<&mut idkfa> = WorldQuery::fetch(&world);
+
+            assert!(query.iter().count() == 0);
+            assert!(query.iter_mut().count() == 0);
         }
-        impl<C: Component> QueryFilter for Without<C>{
-            type Item<'b> = Fetch<'b, C>;
+    }
+    mod test_filter{
+        use super::*;
+        use crate::ECS::storage::test::TestStorage;
+
+        struct idkfa(u8);
+        struct iddqd(u8);
+        impl Component for idkfa{
+            type STORAGE = TestStorage<Self>;
         
-            fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
-                world.fetch::<C>()
-            }
+            const ID: &'static str = "idkfa";
+        }
+        impl Component for iddqd{
+            type STORAGE = TestStorage<Self>;
         
-            fn filter<'qref, 'query: 'qref>(fetched: &'qref Self::Item<'query>, id: &usize) -> bool {
-                fetched.get(id).is_none()
-            }
+            const ID: &'static str = "iddqd";
         }
 
         #[test]
@@ -614,5 +996,124 @@ mod tests{
                 assert!(query.iter().count() == 1)
             }
         }
+        #[test]
+        fn test_get(){
+            let mut world = World::new();
+
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            let mut plain = world.spawn().with(idkfa(5)).finish();
+            let mut excluded = world.spawn().with(idkfa(5)).with(iddqd(10)).finish();
+
+            let mut query: WorldQuery<&mut idkfa, Without<iddqd>> = WorldQuery::fetch(&world);
+
+            assert!(query.get(&plain.id()).is_some());
+            assert!(query.get_mut(&plain.id()).is_some());
+            assert!(query.get_from_token(&mut plain).is_some());
+            assert!(query.get_from_token_mut(&mut plain).is_some());
+
+            // Has the requested Component, but the filter excludes it
+            assert!(query.get(&excluded.id()).is_none());
+            assert!(query.get_mut(&excluded.id()).is_none());
+            assert!(query.get_from_token(&mut excluded).is_none());
+            assert!(query.get_from_token_mut(&mut excluded).is_none());
+            assert!(query.get_array([plain.id(), excluded.id()]).map(|item| item.is_some()) == [true, false]);
+
+            // The Token itself is still fine
+            assert!(excluded.valid());
+        }
+        #[test]
+        fn test_without_coexist(){
+            let mut world = World::new();
+
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(iddqd(10)).finish();
+            world.spawn().with(idkfa(5)).with(iddqd(10)).finish();
+
+            let mut query: WorldQuery<&mut iddqd, Without<idkfa>> = WorldQuery::fetch(&world);
+            for dqd in query.iter_mut(){
+                dqd.0 = 20;
+            }
+            drop(query);
+
+            assert!(world.fetch::<iddqd>().get(&0).unwrap().0 == 20);
+            assert!(world.fetch::<iddqd>().get(&1).unwrap().0 == 10);
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: Tried to mutably fetch Component idkfa while it's borrowed elsewhere")]
+        fn test_without_conflict(){
+            let mut world = World::new();
+
+            world.register_comp::<idkfa>();
+
+            // SHOULD PANIC
+            let _query: WorldQuery<&mut idkfa, Without<idkfa>> = WorldQuery::fetch(&world);
+        }
+        #[test]
+        fn test_where(){
+            struct Active(bool);
+            impl Component for Active{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "Active";
+            }
+            struct IsActive;
+            impl Predicate<Active> for IsActive{
+                fn test(comp: &Active) -> bool {
+                    comp.0
+                }
+            }
+
+            let mut world = World::new();
+
+            world.register_comp::<idkfa>();
+            world.register_comp::<Active>();
+
+            world.spawn().with(idkfa(0)).with(Active(true)).finish();
+            world.spawn().with(idkfa(1)).with(Active(false)).finish();
+            world.spawn().with(idkfa(2)).finish();
+            world.spawn().with(idkfa(3)).with(Active(true)).finish();
+
+            let query: WorldQuery<&idkfa, Where<Active, IsActive>> = WorldQuery::fetch(&world);
+
+            assert!(query.iter().map(|kfa| kfa.0).collect::<Vec<_>>() == [0, 3]);
+            assert!(query.get(&1).is_none());
+            assert!(query.get(&3).is_some());
+        }
+    }
+    #[cfg(feature = "rayon")]
+    mod test_par{
+        use super::*;
+        use crate::ECS::storage::test::TestStorage;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        struct idkfa(u32);
+        impl Component for idkfa{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "idkfa";
+        }
+
+        #[test]
+        fn test(){
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            for i in 0..1000{
+                world.spawn().with(idkfa(i)).finish();
+            }
+
+            let mut query: WorldQuery<&mut idkfa, ()> = WorldQuery::fetch(&world);
+
+            query.par_for_each_mut(|kfa| kfa.0 *= 2);
+
+            let sum = AtomicU32::new(0);
+            query.par_for_each(|kfa| {sum.fetch_add(kfa.0, Ordering::Relaxed);});
+
+            assert!(sum.into_inner() == query.iter().map(|kfa| kfa.0).sum::<u32>());
+        }
     }
 }
\ No newline at end of file
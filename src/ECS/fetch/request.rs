@@ -13,10 +13,17 @@ use ECS::events::Event;
 /// Event Readers and Writers, the Command and Trigger Writers, as well as Tuples up to 4 elements
 /// 
 /// The return type `Item` is typically the type the trait gets implemented on
+/// 
+/// `describe` lists what the Request accesses, the Dispatcher uses it to verify the World has everything registered.  
+/// It describes nothing by default, so make sure to implement it for custom Requests
 pub trait RequestData{
     type Item<'b>;
 
     fn fetch<'a>(world: &'a World) -> Self::Item<'a>;
+    /// Describe the World data this Request accesses
+    fn describe() -> Vec<super::Access>{
+        Vec::new()
+    }
 }
 
 /// # System resource Request
@@ -65,13 +72,20 @@ impl<'a, D: RequestData> DerefMut for Request<'a, D>{
 /// 
 /// Query automatically validates Tokens in Getter functions, they can also be  
 /// manually validated via `validate_token`
-pub struct Query<D: super::QueryData, F: super::QueryFilter>(PhantomData<(D, F)>);
+/// 
+/// The Filter defaults to `()`, which lets every Entity through
+pub struct Query<D: super::QueryData, F: super::QueryFilter = ()>(PhantomData<(D, F)>);
 impl <D: super::QueryData, F: super::QueryFilter> RequestData for Query<D, F>{
     type Item<'b> = super::WorldQuery<'b, D, F>;
 
     fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
         super::WorldQuery::fetch(world)
     }
+    fn describe() -> Vec<super::Access> {
+        let mut access = D::describe();
+        access.extend(F::describe());
+        access
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -84,6 +98,9 @@ impl<R: Resource> RequestData for &R{
     fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
         world.fetch_res()
     }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::Res(R::ID)]
+    }
 }
 impl<R: Resource> RequestData for &mut R{
     type Item<'b> = super::FetchResMut<'b, R>;
@@ -91,6 +108,9 @@ impl<R: Resource> RequestData for &mut R{
     fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
         world.fetch_res_mut()
     }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::ResMut(R::ID)]
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -107,6 +127,43 @@ impl<E: Event> RequestData for ReadEvent<E>{
     fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
         world.get_event_reader()
     }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::EventRead(E::ID)]
+    }
+}
+
+/// # All Events Reader Request
+/// An identifier for `super::AllEventReader` to make Event queue acquisition for reading every alive event easier
+/// 
+/// It borrows the current frame's queue as well, so it can't be requested alongside a `WriteEvent` of the same Event
+pub struct ReadAllEvents<E: Event>(PhantomData<E>);
+
+impl<E: Event> RequestData for ReadAllEvents<E>{
+    type Item<'b> = super::AllEventReader<'b, E>;
+    
+    fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
+        world.get_event_all_reader()
+    }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::EventRead(E::ID)]
+    }
+}
+
+/// # Consuming Event Reader Request
+/// An identifier for `super::ConsumingReader` to make Event queue acquisition for consuming events easier
+/// 
+/// It borrows the queue mutably, so it can't be requested alongside a `ReadEvent` of the same Event
+pub struct ConsumeEvent<E: Event>(PhantomData<E>);
+
+impl<E: Event> RequestData for ConsumeEvent<E>{
+    type Item<'b> = super::ConsumingReader<'b, E>;
+    
+    fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
+        world.get_event_consumer()
+    }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::EventConsume(E::ID)]
+    }
 }
 
 /// # Event Writer Request
@@ -118,6 +175,9 @@ impl<E: Event> RequestData for WriteEvent<E>{
     fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
         world.get_event_writer()
     }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::EventWrite(E::ID)]
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -126,7 +186,12 @@ impl<E: Event> RequestData for WriteEvent<E>{
 
 /// # Commands Request
 /// An identifier for `super::CommandWriter` to make command queue acquisition easier
+/// 
+/// The queued Commands are applied at the end of the same Logic Tick, see `super::CommandWriter`
 pub struct Commands;
+/// # Command Results Request
+/// An identifier for `super::ResultReader` to make Ticketed Command result acquisition easier
+pub struct CommandResults;
 /// # Triggers Request
 /// An identifier for `super::TriggerWriter` to make trigger queue acquisition easier
 pub struct Triggers;
@@ -137,6 +202,33 @@ impl RequestData for Commands{
     fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
         world.get_command_writer()
     }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::Commands]
+    }
+}
+impl RequestData for CommandResults{
+    type Item<'b> = super::ResultReader<'b>;
+
+    fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
+        world.get_result_reader()
+    }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::Commands]
+    }
+}
+/// # Trigger Payloads Request
+/// An identifier for `super::PayloadReader` to make trigger payload acquisition easier
+pub struct TriggerPayloads;
+
+impl RequestData for TriggerPayloads{
+    type Item<'b> = super::PayloadReader<'b>;
+
+    fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
+        world.get_payload_reader()
+    }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::Triggers]
+    }
 }
 impl RequestData for Triggers{
     type Item<'b> = super::TriggerWriter<'b>;
@@ -144,6 +236,9 @@ impl RequestData for Triggers{
     fn fetch<'a>(world: &'a World) -> Self::Item<'a> {
         world.get_trigger_writer()
     }
+    fn describe() -> Vec<super::Access> {
+        vec![super::Access::Triggers]
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -152,9 +247,7 @@ impl RequestData for Triggers{
 impl RequestData for (){
     type Item<'b> = ();
 
-    fn fetch<'a>(_world: &'a World) -> Self::Item<'a> {
-        ()
-    }
+    fn fetch<'a>(_world: &'a World) -> Self::Item<'a> {}
 }
 
 macro_rules! request_impl {
@@ -166,6 +259,11 @@ macro_rules! request_impl {
             fn fetch<'a>(World: &'a World) -> Self::Item<'a> {
                 ($($x::fetch(World)), *)
             }
+            fn describe() -> Vec<super::Access> {
+                let mut access = Vec::new();
+                $(access.extend($x::describe());)*
+                access
+            }
         }
     }
 }
@@ -333,6 +431,48 @@ mod tests{
             let _request_a: Request<'_, (ReadEvent<idkfa>, WriteEvent<iddqd>)> = Request::fetch(&world);
             let _request_b: Request<'_, (ReadEvent<idkfa>, WriteEvent<iddqd>)> = Request::fetch(&world);
         }
+        #[test]
+        fn test_consume(){
+            struct idclip(u8);
+            impl Event for idclip{
+                const ID: &'static str = "idclip";
+            }
+
+            let mut world = World::new();
+            world.register_event::<idclip>();
+
+            {
+                let mut writer = world.get_event_writer::<idclip>();
+                for i in 0..5{
+                    writer.send(idclip(i));
+                }
+            }
+            world.end_tick();
+
+            // The first consumer takes only what it wants
+            {
+                let mut request: Request<'_, ConsumeEvent<idclip>> = Request::fetch(&world);
+                assert!(request.consume().unwrap().0 == 0);
+                assert!(request.consume().unwrap().0 == 1);
+            }
+            // The second one gets the rest
+            {
+                let mut request: Request<'_, ConsumeEvent<idclip>> = Request::fetch(&world);
+                assert!(request.consume_all().map(|event| event.0).collect::<Vec<_>>() == [2, 3, 4]);
+                assert!(request.consume().is_none());
+            }
+
+            assert!(world.get_event_reader::<idclip>().event_count() == 0);
+        }
+        #[test]
+        #[should_panic]
+        fn test_consume_overlap_panic(){
+            let mut world = World::new();
+            world.register_event::<idkfa>();
+
+            let _request_a: Request<'_, ReadEvent<idkfa>> = Request::fetch(&world);
+            let _request_b: Request<'_, ConsumeEvent<idkfa>> = Request::fetch(&world);
+        }
     }
     mod test_commands{
         use super::*;
@@ -382,6 +522,27 @@ mod tests{
             assert!(request.trigger_count() == 2)
         }
         #[test]
+        fn test_payload(){
+            let mut world = World::new();
+
+            {
+                let mut request: Request<'_, Triggers> = Request::fetch(&world);
+                request.send_with("idkfa", 5u32);
+                request.send_with("idkfa", 10u32);
+            }
+            // Payloads are readable once the Triggers fire
+            assert!(world.take_triggers() == ["idkfa", "idkfa"]);
+
+            let mut request: Request<'_, TriggerPayloads> = Request::fetch(&world);
+
+            assert!(request.payload_count("idkfa") == 2);
+            // Wrong type is left in place
+            assert!(request.take::<u8>("idkfa").is_none());
+            assert!(request.take::<u32>("idkfa") == Some(5));
+            assert!(request.take::<u32>("idkfa") == Some(10));
+            assert!(request.take::<u32>("idkfa").is_none());
+        }
+        #[test]
         #[should_panic]
         fn test_illegal_fetch(){
             let world = World::new();
@@ -10,5 +10,6 @@ pub mod entity;
 pub mod events;
 pub mod commands;
 pub mod fetch;
+pub mod timers;
 
 pub mod prelude;
\ No newline at end of file
@@ -1,35 +1,85 @@
 pub use super::{
-    comp::Component,
-    storage::Storage,
-    system::System,
-    world::World,
+    comp::{
+        Component,
+        ComponentSet,
+        SnapshotableComponent,
+        SerializableComponent
+    },
+    storage::{
+        Storage,
+        IterStorage,
+        DefaultStorage,
+        HashMapStorage,
+        StorageStat
+    },
+    system::{
+        System,
+        ExclusiveSystem
+    },
+    world::{
+        World,
+        WorldSnapshot,
+        Scene,
+        SceneEntity,
+        RegistrationReport
+    },
     resource::{
         Resource,
-        DeltaT
+        DefaultResource,
+        DeltaT,
+        LogicPause,
+        Rng
     },
     dispatcher::{
         Dispatcher,
         RunOrder,
-        SystemType
+        SystemType,
+        SystemSection,
+        SystemQueue,
+        DispatchError
     },
     events::{
         Event,
-        ExitApp
+        EventStamp,
+        ExitApp,
+        ComponentRemoved
+    },
+    commands::{
+        Command,
+        TicketedCommand,
+        Ticket
+    },
+    timers::{
+        Timers,
+        TimerSystem
+    },
+    entity::{
+        Token,
+        Bundle,
+        IdStrategy
     },
-    commands::Command,
-    entity::Token,
     fetch::{
         // -- Query --
         Query,
         QueryData,
         QueryFilter,
+        With,
+        Without,
+        Where,
+        Predicate,
         // -- Events --
         ReadEvent,
+        ReadAllEvents,
+        ConsumeEvent,
         WriteEvent,
         // -- Requests --
         Request,
         RequestData,
         Triggers,
-        Commands
+        TriggerPayloads,
+        Commands,
+        CommandResults,
+        // -- Misc --
+        Access
     }
 };
\ No newline at end of file
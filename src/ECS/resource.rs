@@ -1,3 +1,9 @@
+use std::any::Any;
+use std::ops::{Deref, DerefMut};
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
 /// # System Resource trait
 /// Defines a Resource that can be shared between systems
 /// 
@@ -11,6 +17,28 @@ pub trait Resource: 'static{
     fn new() -> Self;
 }
 
+/// # Default Resource trait
+/// Defines a Resource that is created with it's `Default` implementation
+/// 
+/// Implement it instead of `Resource` to skip writing `new` by hand,  
+/// `Resource` gets implemented automatically.  
+/// Resources that need custom initialization should implement `Resource` directly
+/// 
+/// `RES_ID` becomes the Resource's `ID`, it's named differently so `T::ID` stays unambiguous
+/// 
+/// ## WARNING
+/// Make sure your Resource ID does not collide with other IDs from other plugins
+pub trait DefaultResource: Default + 'static{
+    const RES_ID: &'static str;
+}
+impl<T: DefaultResource> Resource for T{
+    const ID: &'static str = T::RES_ID;
+
+    fn new() -> Self {
+        T::default()
+    }
+}
+
 /// # Resource trait Wrapper
 /// A wrapper trait for Resources to safely store them in the World
 /// 
@@ -18,12 +46,17 @@ pub trait Resource: 'static{
 pub(crate) trait ResourceWrapper{
     /// Get the underlying Resource's ID
     fn id(&self) -> &'static str;
+    /// Get the underlying Resource as `Any`, for when the type isn't known
+    fn as_any(&self) -> &dyn Any;
 }
 
 impl<T: Resource> ResourceWrapper for T{
     fn id(&self) -> &'static str {
         T::ID
     }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 impl dyn ResourceWrapper{
@@ -53,6 +86,19 @@ impl dyn ResourceWrapper{
             None
         }
     }
+    /// Downcast the boxed Resource into a box of `T` resource
+    ///
+    /// Returns the box back if the ID of the `T` resource does not match the underlying Resource's ID
+    pub fn downcast<T: Resource>(self: Box<Self>) -> Result<Box<T>, Box<Self>>{
+        if T::ID == self.id(){
+            // SAFETY: We have a check for matching IDs beforehand
+            Ok(unsafe {
+                Box::from_raw(Box::into_raw(self) as *mut T)
+            })
+        }else{
+            Err(self)
+        }
+    }
 }
 
 /// # Delta Time Resources
@@ -60,6 +106,7 @@ impl dyn ResourceWrapper{
 /// - Time since last frame/Logic frame
 /// - Number of times Staller and Logic loops have ran
 /// - Time since the app has started
+/// 
 /// Provides methods to get the Time Deltas in Microseconds as `u128` and Seconds as `f32`
 pub struct DeltaT{
     delta_frame: u128,
@@ -136,4 +183,68 @@ impl Resource for DeltaT{
             app_start: std::time::Instant::now()
         }
     }
-}
\ No newline at end of file
+}
+
+/// # Logic Pause Resource
+/// Pauses the Logic Loop while it's set
+/// 
+/// Preprocessors and Postprocessors keep running while the Logic is paused,  
+/// so input and rendering stay responsive, e.g. in pause menus.  
+/// Systems can pause and resume from within the loop, the Dispatcher checks it before every Logic step
+pub struct LogicPause{
+    paused: bool
+}
+impl LogicPause{
+    /// Pause or resume the Logic Loop
+    pub fn set_paused(&mut self, paused: bool){
+        self.paused = paused;
+    }
+    /// Check if the Logic Loop is paused
+    pub fn is_paused(&self) -> bool{
+        self.paused
+    }
+}
+impl Resource for LogicPause{
+    const ID: &'static str = "LogicPause";
+
+    fn new() -> Self {
+        Self{
+            paused: false
+        }
+    }
+}
+
+/// # Seeded RNG Resource
+/// A random number generator that gives the same numbers for the same seed
+/// 
+/// Use it over `rand::random` wherever the simulation has to stay deterministic,  
+/// such as Lockstep networking or replays.  
+/// It starts with a seed of `0`, use `reseed` to change it
+/// 
+/// Dereferences to `StdRng`, so all of `rand::Rng` methods are available
+pub struct Rng(StdRng);
+impl Rng{
+    /// Restart the generator with the given seed
+    pub fn reseed(&mut self, seed: u64){
+        self.0 = StdRng::seed_from_u64(seed);
+    }
+}
+impl Deref for Rng{
+    type Target = StdRng;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Rng{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl Resource for Rng{
+    const ID: &'static str = "Rng";
+
+    fn new() -> Self {
+        Self(StdRng::seed_from_u64(0))
+    }
+}
@@ -1,7 +1,11 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
 use std::ops::{Deref, DerefMut};
 
 use super::comp::Component;
 use super::entity::Token;
+use super::world::World;
 
 /// # Component Storage trait
 /// Specifies some basic functions for the storage to do
@@ -21,8 +25,14 @@ pub trait Storage<T: Component>{
         self.insert(token.id(), comp);
     }
 
+    /// Remove the specified Entity's Component from this Storage and hand it back
+    fn take(&mut self, id: &usize) -> Option<T>;
     /// Remove the specified Entity's Component from this Storage
-    fn remove(&mut self, id: &usize);
+    /// 
+    /// Drops what `take` hands back by default
+    fn remove(&mut self, id: &usize){
+        self.take(id);
+    }
     /// Remove the Component from the Entity referenced by the Token from this Storage
     /// 
     /// It's recommended to ensure the Token is valid beforehand
@@ -56,10 +66,175 @@ pub trait Storage<T: Component>{
         }
         self.get_mut(&token.id())
     }
+    /// Get mutable references to two different Entities' Components at once
+    /// 
+    /// Returns None if `a` and `b` are the same Entity or either of them doesn't have the Component
+    /// 
+    /// By default it looks both up with `get_mut`, so `get_mut` must never move Components around.  
+    /// Storages that can hand out both borrows safely in one go should override it
+    fn get2_mut(&mut self, a: &usize, b: &usize) -> Option<(&mut T, &mut T)>{
+        if a == b{
+            return None
+        }
+        let a: *mut T = self.get_mut(a)?;
+        let b = self.get_mut(b)?;
+        // SAFETY: Different Entities never share a Component, and looking `b` up doesn't move `a`
+        Some((unsafe{ &mut *a }, b))
+    }
+    /// Get a mutable reference to the specified Entity's Component,  
+    /// inserting the one made by `f` first if the Entity doesn't have it
+    /// 
+    /// By default it looks the Component up twice, Storages that can do it in one go should override it
+    fn get_or_insert_with(&mut self, id: usize, f: impl FnOnce() -> T) -> &mut T{
+        if self.get(&id).is_none(){
+            self.insert(id, f());
+        }
+        // Unwrap: We just made sure it's there
+        self.get_mut(&id).unwrap()
+    }
 
     /// Get amount of components in this storage
     fn len(&self) -> usize;
+    /// Check if this storage has no components
+    fn is_empty(&self) -> bool{
+        self.len() == 0
+    }
+
+    /// Rebuild the storage's layout for better locality, e.g. sorting by Entity ID
+    /// 
+    /// Meant to be called when a hitch is fine, like on a loading screen.  
+    /// It does nothing by default, Storages with a layout to restore should override it
+    fn defragment(&mut self){}
+}
+
+/// # Iterable Storage trait
+/// An extension of `Storage` for Storages that can walk over all of their Components
+/// 
+/// Every Storage shipped with the engine implements it,  
+/// it's needed for things like `World::export_comp`
+pub trait IterStorage<T: Component>: Storage<T>{
+    /// Iterate over all Components in this storage alongside their Entity IDs
+    /// 
+    /// The order is up to the Storage, use `iter_ordered` if you need a stable one
+    fn iter(&self) -> impl Iterator<Item = (usize, &T)>;
+    /// Iterate over all Components in this storage in ascending Entity ID order
+    /// 
+    /// Sorts the Components by default, Storages that already keep them sorted can override it
+    fn iter_ordered(&self) -> impl Iterator<Item = (usize, &T)>{
+        let mut items: Vec<(usize, &T)> = self.iter().collect();
+        items.sort_by_key(|(id, _)| *id);
+        items.into_iter()
+    }
+}
+
+/// # Default Storage
+/// The Storage to reach for when a Component has no special needs
+/// 
+/// Use it as `type STORAGE = DefaultStorage<Self>;`
+pub type DefaultStorage<C> = HashMapStorage<C>;
+
+/// # HashMap Storage
+/// A general purpose Storage backed by a `HashMap`
+/// 
+/// Works well for both common and sparse Components, 
+/// with no ordering guarantees
+#[derive(Clone)]
+pub struct HashMapStorage<C: Component>{
+    inner: HashMap<usize, C, StorageHasher>
 }
+impl<C: Component> Storage<C> for HashMapStorage<C>{
+    fn new() -> Self {
+        Self{
+            inner: HashMap::default()
+        }
+    }
+
+    fn insert(&mut self, id: usize, comp: C) {
+        self.inner.insert(id, comp);
+    }
+    fn take(&mut self, id: &usize) -> Option<C> {
+        self.inner.remove(id)
+    }
+
+    fn get(&self, id: &usize) -> Option<&C> {
+        self.inner.get(id)
+    }
+    fn get_mut(&mut self, id: &usize) -> Option<&mut C> {
+        self.inner.get_mut(id)
+    }
+    fn get2_mut(&mut self, a: &usize, b: &usize) -> Option<(&mut C, &mut C)> {
+        // `get_disjoint_mut` panics on overlapping keys
+        if a == b{
+            return None
+        }
+        match self.inner.get_disjoint_mut([a, b]){
+            [Some(a), Some(b)] => Some((a, b)),
+            _ => None
+        }
+    }
+    fn get_or_insert_with(&mut self, id: usize, f: impl FnOnce() -> C) -> &mut C {
+        self.inner.entry(id).or_insert_with(f)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+impl<C: Component> IterStorage<C> for HashMapStorage<C>{
+    fn iter(&self) -> impl Iterator<Item = (usize, &C)> {
+        self.inner.iter().map(|(id, comp)| (*id, comp))
+    }
+}
+
+/// # Storage Hasher
+/// The Hasher used by the in-crate Storages for their Entity ID keys
+/// 
+/// It's the standard SipHash by default, enable the `fast-hash` feature to use `FxBuildHasher` instead
+#[cfg(not(feature = "fast-hash"))]
+pub type StorageHasher = std::collections::hash_map::RandomState;
+/// # Storage Hasher
+/// The Hasher used by the in-crate Storages for their Entity ID keys
+/// 
+/// The `fast-hash` feature is enabled, so it's `FxBuildHasher`
+#[cfg(feature = "fast-hash")]
+pub type StorageHasher = FxBuildHasher;
+
+/// # Fx Hasher
+/// A fast, non-cryptographic Hasher for integer keys, such as Entity IDs
+/// 
+/// Based on the Hasher used within rustc.  
+/// It's easy to craft colliding keys for it, so only use it for keys you control
+#[derive(Default, Clone, Copy)]
+pub struct FxHasher{
+    hash: u64
+}
+impl FxHasher{
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    fn add_to_hash(&mut self, word: u64){
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+    }
+}
+impl Hasher for FxHasher{
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8){
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.add_to_hash(u64::from_le_bytes(word));
+        }
+    }
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+/// Builder for `FxHasher`, to be used with `HashMap`s
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
 
 /// # Storage trait Container
 /// Wraps a Component's `STORAGE` to safely store it within the World
@@ -78,6 +253,13 @@ impl<T: Component> StorageContainer<T>{
         }
     }
 }
+impl<T: Component> Clone for StorageContainer<T> where T::STORAGE: Clone{
+    fn clone(&self) -> Self {
+        Self{
+            inner: self.inner.clone()
+        }
+    }
+}
 impl<T: Component> Deref for StorageContainer<T>{
     type Target = T::STORAGE;
 
@@ -91,22 +273,87 @@ impl<T: Component> DerefMut for StorageContainer<T>{
     }
 }
 
+/// # Storage Statistics
+/// A rough look at a Component's Storage, to check whether it fits the Component
+/// 
+/// `storage_kind` is the name of the Storage type, without the path or generics.  
+/// `bytes_estimate` only counts the Components themselves, not the Storage's overhead
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageStat{
+    pub id: &'static str,
+    pub entity_count: usize,
+    pub storage_kind: &'static str,
+    pub bytes_estimate: usize
+}
+
 /// # Storage Container Wrapper trait
 /// A dyn-compatible wrapper for StorageContainer for the World to store with
 /// 
-/// Provides ability to remove a Component of the specified entity for easier cleanup,  
+/// Provides ability to insert and remove a Component of the specified entity without knowing it's type,  
 /// as well as Downcast methods to get the underlying Containers
 pub(crate) trait StorageWrapper{
+    /// Insert a type-erased Component for the specified Entity into this storage
+    /// 
+    /// Returns the value back if it isn't the underlying Container's Component type
+    fn insert_any(&mut self, id: usize, comp: Box<dyn Any>) -> Result<(), Box<dyn Any>>;
+    /// Get a type-erased reference to the specified Entity's Component
+    fn get_any(&self, id: usize) -> Option<&dyn Any>;
+    /// Take the specified Entity's Component out of this storage, to run its removal hook on
+    fn take_removed(&mut self, id: usize) -> Option<Box<dyn RemovedComponent>>;
     /// Remove a specified Entity's Component from this storage
     fn remove(&mut self, id: usize);
+    /// Defragment the underlying Storage
+    fn defragment(&mut self);
+    /// Get the statistics of the underlying Storage
+    fn stat(&self) -> StorageStat;
     /// Get the underlying Container's Component ID
     fn comp_id(&self) -> &'static str;
 }
 
+/// # Removed Component trait
+/// A type-erased Component that was already taken out of its Storage
+/// 
+/// Lets the World run `on_remove` hooks without holding any Storage borrowed
+pub(crate) trait RemovedComponent{
+    /// Run the Component's `on_remove` hook
+    fn on_remove(&mut self, id: usize, world: &World);
+}
+impl<T: Component> RemovedComponent for T{
+    fn on_remove(&mut self, id: usize, world: &World){
+        Component::on_remove(self, id, world);
+    }
+}
+
 impl<T: Component> StorageWrapper for StorageContainer<T>{
+    fn insert_any(&mut self, id: usize, comp: Box<dyn Any>) -> Result<(), Box<dyn Any>>{
+        self.inner.insert(id, *comp.downcast::<T>()?);
+        Ok(())
+    }
+    fn get_any(&self, id: usize) -> Option<&dyn Any>{
+        self.inner.get(&id).map(|comp| comp as &dyn Any)
+    }
+    fn take_removed(&mut self, id: usize) -> Option<Box<dyn RemovedComponent>>{
+        self.inner.take(&id).map(|comp| Box::new(comp) as Box<dyn RemovedComponent>)
+    }
     fn remove(&mut self, id: usize){
         self.inner.remove(&id);
     }
+    fn defragment(&mut self){
+        self.inner.defragment();
+    }
+    fn stat(&self) -> StorageStat{
+        // `some::path::HashMapStorage<some::path::Comp>` -> `HashMapStorage`
+        let kind = std::any::type_name::<T::STORAGE>()
+            .split('<').next().unwrap()
+            .rsplit("::").next().unwrap();
+
+        StorageStat{
+            id: T::ID,
+            entity_count: self.inner.len(),
+            storage_kind: kind,
+            bytes_estimate: size_of::<T>() * self.inner.len()
+        }
+    }
 
     fn comp_id(&self) -> &'static str {
         T::ID
@@ -147,6 +394,7 @@ pub(crate) mod test{
     use super::*;
     use std::collections::HashMap;
 
+    #[derive(Clone)]
     pub struct TestStorage<C: Component>{
         inner: HashMap<usize, C>
     }
@@ -160,8 +408,8 @@ pub(crate) mod test{
         fn insert(&mut self, id: usize, comp: C) {
             self.inner.insert(id, comp);
         }
-        fn remove(&mut self, id: &usize) {
-            self.inner.remove(id);
+        fn take(&mut self, id: &usize) -> Option<C> {
+            self.inner.remove(id)
         }
 
         fn get(&self, id: &usize) -> Option<&C> {
@@ -174,6 +422,55 @@ pub(crate) mod test{
             self.inner.len()
         }
     }
+    impl<C: Component> IterStorage<C> for TestStorage<C>{
+        fn iter(&self) -> impl Iterator<Item = (usize, &C)> {
+            self.inner.iter().map(|(id, comp)| (*id, comp))
+        }
+    }
+
+    /// Keeps Components in insertion order, scrambled by removals until defragmented
+    struct SortedStorage<C: Component>{
+        inner: Vec<(usize, C)>
+    }
+    impl<C: Component> Storage<C> for SortedStorage<C>{
+        fn new() -> Self {
+            Self{
+                inner: Vec::new()
+            }
+        }
+        fn insert(&mut self, id: usize, comp: C) {
+            self.remove(&id);
+            self.inner.push((id, comp));
+        }
+        fn take(&mut self, id: &usize) -> Option<C> {
+            let index = self.inner.iter().position(|(idx, _)| idx == id)?;
+            Some(self.inner.swap_remove(index).1)
+        }
+        fn get(&self, id: &usize) -> Option<&C> {
+            self.inner.iter().find(|(idx, _)| idx == id).map(|(_, comp)| comp)
+        }
+        fn get_mut(&mut self, id: &usize) -> Option<&mut C> {
+            self.inner.iter_mut().find(|(idx, _)| idx == id).map(|(_, comp)| comp)
+        }
+        fn get2_mut(&mut self, a: &usize, b: &usize) -> Option<(&mut C, &mut C)> {
+            let a = self.inner.iter().position(|(idx, _)| idx == a)?;
+            let b = self.inner.iter().position(|(idx, _)| idx == b)?;
+            // Errors on `a == b`
+            let [(_, a), (_, b)] = self.inner.get_disjoint_mut([a, b]).ok()?;
+            Some((a, b))
+        }
+        fn len(&self) -> usize {
+            self.inner.len()
+        }
+        fn defragment(&mut self) {
+            self.inner.sort_by_key(|(id, _)| *id);
+        }
+    }
+    impl<C: Component> IterStorage<C> for SortedStorage<C>{
+        fn iter(&self) -> impl Iterator<Item = (usize, &C)> {
+            self.inner.iter().map(|(id, comp)| (*id, comp))
+        }
+    }
 
     struct idkfa(u8);
     impl Component for idkfa{
@@ -202,6 +499,48 @@ pub(crate) mod test{
         assert!(storage.get(&0).unwrap().0 == 10)
     }
     #[test]
+    fn test_get_or_insert_with(){
+        fn check<S: Storage<idkfa>>(){
+            let mut storage = S::new();
+            storage.insert(0, idkfa(5));
+
+            // Present, `f` is not called
+            storage.get_or_insert_with(0, || panic!("Should not be called")).0 += 1;
+            assert!(storage.get(&0).unwrap().0 == 6);
+
+            // Absent, gets inserted
+            assert!(storage.get_or_insert_with(1, || idkfa(10)).0 == 10);
+            storage.get_or_insert_with(1, || idkfa(0)).0 += 1;
+            assert!(storage.get(&1).unwrap().0 == 11);
+            assert!(storage.len() == 2);
+        }
+
+        check::<HashMapStorage<idkfa>>();
+        check::<TestStorage<idkfa>>();
+    }
+    #[test]
+    fn test_get2_mut(){
+        fn check<S: Storage<idkfa>>(){
+            let mut storage = S::new();
+            storage.insert(0, idkfa(5));
+            storage.insert(1, idkfa(10));
+
+            let (a, b) = storage.get2_mut(&0, &1).unwrap();
+            std::mem::swap(a, b);
+            assert!(storage.get(&0).unwrap().0 == 10);
+            assert!(storage.get(&1).unwrap().0 == 5);
+
+            assert!(storage.get2_mut(&0, &0).is_none());
+            assert!(storage.get2_mut(&0, &2).is_none());
+            assert!(storage.get2_mut(&2, &1).is_none());
+        }
+
+        check::<HashMapStorage<idkfa>>();
+        // Goes through the provided `get2_mut`
+        check::<TestStorage<idkfa>>();
+        check::<SortedStorage<idkfa>>();
+    }
+    #[test]
     fn test_get_token(){
         let mut storage = TestStorage::new();
         let token = crate::ECS::entity::Entity::new(0).get_token();
@@ -212,4 +551,137 @@ pub(crate) mod test{
         storage.get_from_token_mut(&token).unwrap().0 = 10;
         assert!(storage.get_from_token(&token).unwrap().0 == 10)
     }
+    #[test]
+    fn test_default_storage(){
+        struct iddqd(u8);
+        impl Component for iddqd{
+            type STORAGE = DefaultStorage<Self>;
+
+            const ID: &'static str = "iddqd";
+        }
+
+        let mut storage = <iddqd as Component>::STORAGE::new();
+
+        storage.insert(0, iddqd(5));
+        assert!(storage.get(&0).unwrap().0 == 5);
+        storage.remove(&0);
+        assert!(storage.is_empty());
+    }
+    #[test]
+    fn test_insert_any(){
+        let mut container = StorageContainer::<idkfa>::new();
+        let wrapper: &mut dyn StorageWrapper = &mut container;
+
+        assert!(wrapper.insert_any(0, Box::new(idkfa(5))).is_ok());
+        // Wrong type gets handed back
+        assert!(wrapper.insert_any(1, Box::new(5u8)).is_err());
+
+        let container = wrapper.downcast_ref::<idkfa>().unwrap();
+        assert!(container.get(&0).unwrap().0 == 5);
+        assert!(container.get(&1).is_none());
+    }
+    #[test]
+    fn test_defragment(){
+        struct iddqd(usize);
+        impl Component for iddqd{
+            type STORAGE = SortedStorage<Self>;
+
+            const ID: &'static str = "iddqd";
+        }
+
+        let mut world = World::new();
+        world.register_comp::<iddqd>();
+
+        for id in 0..6{
+            world.spawn().with(iddqd(id)).finish();
+        }
+        // Scramble it
+        world.despawn(1);
+        world.despawn(3);
+        world.spawn().with(iddqd(1)).finish();
+
+        world.defragment();
+
+        let storage = world.fetch::<iddqd>();
+        assert!(storage.inner.iter().map(|(id, _)| *id).collect::<Vec<_>>() == [0, 1, 2, 4, 5]);
+        assert!(storage.inner.iter().all(|(id, comp)| *id == comp.0));
+    }
+    #[test]
+    fn test_iter_ordered(){
+        fn fill<S: IterStorage<idkfa>>() -> S{
+            let mut storage = S::new();
+            for id in [4, 0, 7, 2, 9, 1]{
+                storage.insert(id, idkfa(id as u8));
+            }
+            // Swap removal scrambles the SortedStorage
+            storage.remove(&0);
+            storage.insert(3, idkfa(3));
+            storage
+        }
+        fn ordered<S: IterStorage<idkfa>>(storage: &S) -> Vec<(usize, u8)>{
+            storage.iter_ordered().map(|(id, comp)| (id, comp.0)).collect()
+        }
+
+        let expected = vec![(1, 1), (2, 2), (3, 3), (4, 4), (7, 7), (9, 9)];
+
+        assert!(ordered(&fill::<HashMapStorage<idkfa>>()) == expected);
+        assert!(ordered(&fill::<TestStorage<idkfa>>()) == expected);
+        assert!(ordered(&fill::<SortedStorage<idkfa>>()) == expected);
+    }
+    #[test]
+    fn test_stats(){
+        struct iddqd(u64);
+        impl Component for iddqd{
+            type STORAGE = DefaultStorage<Self>;
+
+            const ID: &'static str = "iddqd";
+        }
+
+        let mut world = World::new();
+        world.register_comp::<idkfa>();
+        world.register_comp::<iddqd>();
+
+        for id in 0..4{
+            world.spawn().with(idkfa(id)).with(iddqd(id as u64)).finish();
+        }
+        world.spawn().with(iddqd(9)).finish();
+
+        let stats = world.storage_stats();
+
+        assert!(stats == [
+            StorageStat{ id: "iddqd", entity_count: 5, storage_kind: "HashMapStorage", bytes_estimate: 40 },
+            StorageStat{ id: "idkfa", entity_count: 4, storage_kind: "TestStorage", bytes_estimate: 4 }
+        ]);
+    }
+    #[test]
+    fn test_hasher(){
+        use std::any::TypeId;
+        use std::hash::BuildHasher;
+
+        #[cfg(feature = "fast-hash")]
+        assert!(TypeId::of::<StorageHasher>() == TypeId::of::<FxBuildHasher>());
+        #[cfg(not(feature = "fast-hash"))]
+        assert!(TypeId::of::<StorageHasher>() == TypeId::of::<std::collections::hash_map::RandomState>());
+
+        // Same key, same hash, and neighbouring IDs don't collide
+        assert!(FxBuildHasher::default().hash_one(5usize) == FxBuildHasher::default().hash_one(5usize));
+        assert!(FxBuildHasher::default().hash_one(5usize) != FxBuildHasher::default().hash_one(6usize));
+
+        let mut map: HashMap<usize, usize, FxBuildHasher> = HashMap::default();
+        for id in 0..1000{
+            map.insert(id, id * 2);
+        }
+        assert!(map.len() == 1000);
+        assert!((0..1000).all(|id| map.get(&id) == Some(&(id * 2))));
+    }
+    #[test]
+    fn test_get_any(){
+        let mut container = StorageContainer::<idkfa>::new();
+        container.insert(0, idkfa(5));
+        let wrapper: &dyn StorageWrapper = &container;
+
+        assert!(wrapper.get_any(0).unwrap().downcast_ref::<idkfa>().unwrap().0 == 5);
+        assert!(wrapper.get_any(0).unwrap().downcast_ref::<u8>().is_none());
+        assert!(wrapper.get_any(1).is_none());
+    }
 }
\ No newline at end of file
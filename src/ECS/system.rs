@@ -5,7 +5,8 @@ use super::fetch::*;
 /// # System trait
 /// Defines a System that will be run on the World
 ///
-/// `Data` is the Data the System wants to request from the World, like Components Query, Resources, Event Readers/Writers, etc.
+/// `Data` is the Data the System wants to request from the World, like Components Query, Resources, Event Readers/Writers, etc.  
+/// Systems that only work on Components simply request a `Query`, Systems that need nothing request `()`
 /// 
 /// `ID` is what the System is identified by in the Dispatcher and in Overrides
 /// 
@@ -22,6 +23,14 @@ use super::fetch::*;
 /// Note: `RUNORD` is only effective with Systems of the same `SystemType`
 /// 
 /// `TYPE` defines where the System should be put within the Execution loop, it is `SystemType::Logic` by default
+/// 
+/// `EXCLUSIVE` marks this System as one that must never run alongside other Systems,  
+/// such as Systems doing I/O or touching global state the Dispatcher can't see.  
+/// Exclusive Systems get a Stage all to themselves, it is `false` by default
+/// 
+/// `TICK_INTERVAL` makes a Logic System run only on every `N`th Tick, for throttling expensive Systems like pathfinding.  
+/// The System runs on Ticks where `World::current_tick` is divisible by it, it is `1` by default.  
+/// It has no effect on Systems of other types
 pub trait System: 'static{
     type Data<'a>: RequestData;
     const ID: &'static str;
@@ -29,6 +38,8 @@ pub trait System: 'static{
     const DEPENDS: &'static [&'static str] = &[];
     const RUNORD: &'static [RunOrder] = &[];
     const TYPE: SystemType = SystemType::Logic;
+    const EXCLUSIVE: bool = false;
+    const TICK_INTERVAL: u64 = 1;
 
     /// Create a new instance of this System
     fn new() -> Self;
@@ -36,6 +47,30 @@ pub trait System: 'static{
     fn execute(&mut self, data: Request<'_, Self::Data<'_>>);
 }
 
+/// # Exclusive System trait
+/// Defines a System that gets the whole World mutably
+/// 
+/// Meant for structural changes that can't be done through a `Request`,  
+/// like spawning Entities or registering Components at runtime.  
+/// It is the sanctioned alternative to the Command Queue for complex setup
+/// 
+/// `ID`, `OVERRIDE`, `DEPENDS`, `RUNORD`, `TYPE` and `TICK_INTERVAL` work the same as with `System`
+/// 
+/// Exclusive Systems always get a Stage all to themselves
+pub trait ExclusiveSystem: 'static{
+    const ID: &'static str;
+    const OVERRIDE: bool = false;
+    const DEPENDS: &'static [&'static str] = &[];
+    const RUNORD: &'static [RunOrder] = &[];
+    const TYPE: SystemType = SystemType::Logic;
+    const TICK_INTERVAL: u64 = 1;
+
+    /// Create a new instance of this System
+    fn new() -> Self;
+    /// Run the System
+    fn execute(&mut self, world: &mut World);
+}
+
 /// # System trait Wrapper
 /// A wrapper trait for Systems to safely store and dispatch them in the Dispatcher
 /// 
@@ -49,8 +84,14 @@ pub(crate) trait SystemWrapper{
     fn run_order(&self) -> &'static [RunOrder];
     /// Get the type of the underlying System
     fn sys_type(&self) -> SystemType;
+    /// Check if the underlying System needs a Stage to itself
+    fn exclusive(&self) -> bool;
+    /// Get the number of Ticks between runs of the underlying System
+    fn tick_interval(&self) -> u64;
+    /// Describe the World data the underlying System requests
+    fn access(&self) -> Vec<Access>;
     /// Run the underlying System with specified World
-    fn execute<'a>(&mut self, world: &'a mut World);
+    fn execute(&mut self, world: &mut World);
     
 }
 
@@ -67,11 +108,54 @@ impl<T: System> SystemWrapper for T{
     fn sys_type(&self) -> SystemType {
         T::TYPE
     }
-    fn execute<'a>(&mut self, world: &'a mut World) {
+    fn exclusive(&self) -> bool {
+        T::EXCLUSIVE
+    }
+    fn tick_interval(&self) -> u64 {
+        T::TICK_INTERVAL
+    }
+    fn access(&self) -> Vec<Access> {
+        T::Data::<'static>::describe()
+    }
+    fn execute(&mut self, world: &mut World) {
         self.execute(Request::fetch(world));
     }
 }
 
+/// # Exclusive System Container
+/// Wraps an Exclusive System so that the Dispatcher can store it alongside normal Systems
+/// 
+/// It is required as blanket implementations for both `System` and `ExclusiveSystem` would conflict
+pub(crate) struct ExclusiveContainer<T: ExclusiveSystem>(pub T);
+
+impl<T: ExclusiveSystem> SystemWrapper for ExclusiveContainer<T>{
+    fn id(&self) -> &'static str {
+        T::ID
+    }   
+    fn depends(&self) -> &'static [&'static str] {
+        T::DEPENDS
+    }
+    fn run_order(&self) -> &'static [RunOrder] {
+        T::RUNORD
+    }
+    fn sys_type(&self) -> SystemType {
+        T::TYPE
+    }
+    fn exclusive(&self) -> bool {
+        true
+    }
+    fn tick_interval(&self) -> u64 {
+        T::TICK_INTERVAL
+    }
+    fn access(&self) -> Vec<Access> {
+        // It gets the whole World, there's nothing specific to describe
+        Vec::new()
+    }
+    fn execute(&mut self, world: &mut World) {
+        self.0.execute(world);
+    }
+}
+
 #[cfg(test)]
 mod tests{
     use super::*;
@@ -168,6 +252,71 @@ mod tests{
             SystemWrapper::execute(&mut test_iter, &mut world);
         }
         #[test]
+        fn test_query_only(){
+
+            struct QueryOnly;
+            impl System for QueryOnly{
+                type Data<'a> = Query<(&'a idkfa, &'a mut iddqd)>;
+            
+                const ID: &'static str = "_test_QueryOnly";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                    for (kfa, dqd) in data.iter_mut(){
+                        dqd.0 += kfa.0;
+                    }
+                }
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(idkfa(5)).with(iddqd(10)).finish();
+
+            let mut test_query_only = QueryOnly::new();
+            SystemWrapper::execute(&mut test_query_only, &mut world);
+
+            assert!(world.fetch::<iddqd>().get(&0).unwrap().0 == 15);
+        }
+        #[test]
+        fn test_query_with_res(){
+            use crate::ECS::resource::DeltaT;
+
+            struct QueryRes;
+            impl System for QueryRes{
+                type Data<'a> = (Query<(&'a idkfa, &'a mut iddqd)>, &'a DeltaT);
+            
+                const ID: &'static str = "_test_QueryRes";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+                    let frame = data.1.frame() as u8;
+                    for (kfa, dqd) in data.0.iter_mut(){
+                        dqd.0 = kfa.0 + frame;
+                    }
+                }
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(idkfa(5)).with(iddqd(10)).finish();
+            world.fetch_res_mut::<DeltaT>().incr_frame();
+
+            let mut test_query_res = QueryRes::new();
+            SystemWrapper::execute(&mut test_query_res, &mut world);
+
+            assert!(world.fetch::<iddqd>().get(&0).unwrap().0 == 6);
+        }
+        #[test]
         fn test_addremove(){
 
             /// PREREQUISTES:
@@ -324,6 +473,28 @@ mod tests{
             SystemWrapper::execute(&mut read, &mut world);
 
         }
+        #[test]
+        fn test_immediate(){
+            struct idclip;
+            impl Event for idclip{
+                const ID: &'static str = "idclip";
+                const IMMEDIATE: bool = true;
+            }
+
+            let mut world = World::new();
+            world.register_event::<idclip>();
+
+            world.get_event_writer::<idclip>().send(idclip);
+            // Visible within the same Tick
+            assert!(world.get_event_reader::<idclip>().event_count() == 1);
+            assert!(world.get_events().get_active_events().contains(&idclip::ID));
+
+            // And gone once the Tick ends
+            world.swap_event_buffers();
+            assert!(world.get_event_reader::<idclip>().event_count() == 0);
+            world.swap_event_buffers();
+            assert!(world.get_event_reader::<idclip>().event_count() == 0);
+        }
     }
     mod test_meta{
         use super::*;
@@ -366,4 +537,85 @@ mod tests{
             SystemWrapper::execute(&mut test_sys, &mut world);
         }
     }
+    mod test_access{
+        use super::*;
+        use crate::ECS::comp::Component;
+        use crate::ECS::resource::DeltaT;
+        use crate::ECS::events::ExitApp;
+        use crate::ECS::storage::test::TestStorage;
+
+        struct idkfa;
+        struct iddqd;
+        impl Component for idkfa{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "idkfa";
+        }
+        impl Component for iddqd{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "iddqd";
+        }
+
+        struct TestSys;
+        impl System for TestSys{
+            type Data<'a> = (Query<&'a mut idkfa, With<iddqd>>, &'a DeltaT, WriteEvent<ExitApp>, Commands);
+        
+            const ID: &'static str = "_test_TestSys";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                
+            }
+        }
+        struct TestExclusive;
+        impl ExclusiveSystem for TestExclusive{
+            const ID: &'static str = "_test_TestExclusive";
+        
+            fn new() -> Self {
+                Self
+            }
+        
+            fn execute(&mut self, _world: &mut World) {
+                
+            }
+        }
+
+        #[test]
+        fn test(){
+            let systems: [Box<dyn SystemWrapper>; 2] = [Box::new(TestSys::new()), Box::new(ExclusiveContainer(TestExclusive::new()))];
+
+            assert!(systems[0].access() == [
+                Access::CompMut("idkfa"),
+                Access::Comp("iddqd"),
+                Access::Res("DeltaT"),
+                Access::EventWrite("_APP_EXIT"),
+                Access::Commands
+            ]);
+            assert!(systems[1].access().is_empty());
+        }
+        #[test]
+        fn test_consume(){
+            struct Consumer;
+            impl System for Consumer{
+                type Data<'a> = ConsumeEvent<ExitApp>;
+            
+                const ID: &'static str = "_test_Consumer";
+            
+                fn new() -> Self {
+                    Self
+                }
+            
+                fn execute(&mut self, _data: Request<'_, Self::Data<'_>>) {
+                    
+                }
+            }
+
+            // Consuming mutates the queue, so it can't pass for a plain read
+            assert!(SystemWrapper::access(&Consumer::new()) == [Access::EventConsume("_APP_EXIT")]);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use super::resource::{Resource, DeltaT};
+use super::system::System;
+use super::fetch::{Request, Triggers};
+
+/// # Timers Resource
+/// Keeps track of delayed and repeating Triggers
+/// 
+/// Timers are advanced by `TimerSystem` using the Logic Delta,  
+/// firing their Trigger once they run out
+/// 
+/// One-shot Timers are removed once fired, repeating Timers get rescheduled
+pub struct Timers{
+    timers: Vec<Timer>
+}
+impl Timers{
+    /// Fire the Trigger once after `delay`
+    pub fn after(&mut self, delay: Duration, trigger: &'static str){
+        self.timers.push(Timer{
+            remaining: delay,
+            interval: None,
+            trigger
+        });
+    }
+    /// Fire the Trigger every `interval`
+    /// 
+    /// Panics if the interval is zero
+    pub fn every(&mut self, interval: Duration, trigger: &'static str){
+        if interval.is_zero(){
+            panic!("ERROR: Attempted to schedule a repeating Timer with zero interval for Trigger {}", trigger)
+        }
+        self.timers.push(Timer{
+            remaining: interval,
+            interval: Some(interval),
+            trigger
+        });
+    }
+    /// Remove all Timers for the given Trigger
+    pub fn cancel(&mut self, trigger: &'static str){
+        self.timers.retain(|timer| timer.trigger != trigger);
+    }
+    /// Get the number of currently scheduled Timers
+    pub fn timer_count(&self) -> usize{
+        self.timers.len()
+    }
+    /// Advance all Timers by `delta`
+    /// 
+    /// Returns the Triggers that have fired, repeating Timers can fire multiple times
+    fn advance(&mut self, delta: Duration) -> Vec<&'static str>{
+        let mut fired = Vec::new();
+
+        self.timers.retain_mut(|timer|{
+            let mut elapsed = delta;
+            loop{
+                if elapsed < timer.remaining{
+                    timer.remaining -= elapsed;
+                    return true
+                }
+                elapsed -= timer.remaining;
+                fired.push(timer.trigger);
+
+                match timer.interval{
+                    Some(interval) => timer.remaining = interval,
+                    // One-shot, we're done with it
+                    None => return false,
+                }
+            }
+        });
+
+        fired
+    }
+}
+impl Resource for Timers{
+    const ID: &'static str = "Timers";
+
+    fn new() -> Self {
+        Self{
+            timers: Vec::new()
+        }
+    }
+}
+
+/// A single scheduled Trigger
+struct Timer{
+    remaining: Duration,
+    interval: Option<Duration>,
+    trigger: &'static str
+}
+
+/// # Timer System
+/// Advances `Timers` every Logic Tick and sends the Triggers of Timers that have ran out
+/// 
+/// As it's a Logic System, the fired Singlefires run within the same Tick
+pub struct TimerSystem;
+impl System for TimerSystem{
+    type Data<'a> = (&'a mut Timers, &'a DeltaT, Triggers);
+    const ID: &'static str = "_Timers";
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn execute(&mut self, mut data: Request<'_, Self::Data<'_>>) {
+        let delta = Duration::from_micros(data.1.delta_logic() as u64);
+
+        for trigger in data.0.advance(delta){
+            data.2.send(trigger);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use crate::ECS::world::World;
+    use crate::ECS::system::SystemWrapper;
+
+    #[test]
+    fn test(){
+        let mut world = World::new();
+        world.register_res::<Timers>();
+
+        world.fetch_res_mut::<Timers>().every(Duration::from_millis(100), "idkfa");
+        world.fetch_res_mut::<Timers>().after(Duration::from_millis(300), "iddqd");
+        world.fetch_res_mut::<DeltaT>().set_delta_logic(250_000, 0.25);
+
+        let mut system = TimerSystem::new();
+
+        SystemWrapper::execute(&mut system, &mut world);
+        let triggers = world.take_triggers();
+        assert!(triggers == ["idkfa", "idkfa"]);
+
+        SystemWrapper::execute(&mut system, &mut world);
+        let triggers = world.take_triggers();
+        assert!(triggers.iter().filter(|trigger| **trigger == "idkfa").count() == 3);
+        assert!(triggers.iter().filter(|trigger| **trigger == "iddqd").count() == 1);
+
+        // The one-shot is gone
+        assert!(world.fetch_res::<Timers>().timer_count() == 1);
+    }
+    #[test]
+    #[should_panic]
+    fn test_zero_interval(){
+        let mut timers = Timers::new();
+        timers.every(Duration::ZERO, "idkfa");
+    }
+}
\ No newline at end of file
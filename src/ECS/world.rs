@@ -1,4 +1,5 @@
-use std::cell::{RefCell, Ref, RefMut};
+use std::any::Any;
+use std::cell::{Cell, RefCell, Ref, RefMut};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use super::events::*;
@@ -21,28 +22,51 @@ use super::commands::*;
 /// Provides methods for registering, removing and accessing the data
 pub struct World{
     entities: BTreeMap<usize, Entity>,
-    next_free: BTreeSet<usize>,
+    next_free: FreeIds,
+    next_id: usize,
+    id_strategy: IdStrategy,
     components: HashMap<&'static str, RefCell<Box<dyn StorageWrapper>>>,
+    snapshotters: HashMap<&'static str, Snapshotter>,
+    serializers: HashMap<&'static str, Serializer>,
     resources: HashMap<&'static str, RefCell<Box<dyn ResourceWrapper>>>,
     events: EventBufferMap,
     triggers: RefCell<Vec<&'static str>>,
-    commands: RefCell<Vec<Box<dyn CommandWrapper>>>
+    immediate_triggers: RefCell<Vec<&'static str>>,
+    pending_payloads: RefCell<TriggerPayloadMap>,
+    trigger_payloads: RefCell<TriggerPayloadMap>,
+    commands: RefCell<Vec<Box<dyn CommandWrapper>>>,
+    next_ticket: Cell<u64>,
+    command_results: RefCell<CommandResultMap>,
+    tick: u64
 }
 impl World{
     /// Create a new, empty World
     pub fn new() -> Self{
         let mut temp = Self{
             entities: BTreeMap::new(),
-            next_free: BTreeSet::new(),
+            next_free: FreeIds::new(),
+            next_id: 0,
+            id_strategy: IdStrategy::default(),
             components: HashMap::new(),
+            snapshotters: HashMap::new(),
+            serializers: HashMap::new(),
             resources: HashMap::new(),
             events: EventBufferMap::new(),
             triggers: RefCell::new(Vec::new()),
-            commands: RefCell::new(Vec::new())
+            immediate_triggers: RefCell::new(Vec::new()),
+            pending_payloads: RefCell::new(HashMap::new()),
+            trigger_payloads: RefCell::new(HashMap::new()),
+            commands: RefCell::new(Vec::new()),
+            next_ticket: Cell::new(0),
+            command_results: RefCell::new(HashMap::new()),
+            tick: 0
         };
 
         temp.register_event::<ExitApp>();
+        temp.register_event::<ComponentRemoved>();
         temp.register_res::<DeltaT>();
+        temp.register_res::<LogicPause>();
+        temp.register_res::<super::dispatcher::SystemQueue>();
 
         temp
     }
@@ -59,9 +83,13 @@ impl World{
             panic!("ERROR: Tried to fetch an unregistered Component: {}", T::ID)
         }
 
+        // Unwrap: We have a check for an invalid Component earlier
+        let Ok(storage) = self.components.get(T::ID).unwrap().try_borrow() else{
+            panic!("ERROR: Tried to fetch Component {} while it's mutably borrowed elsewhere", T::ID)
+        };
+
         Ref::map(
-            // Unwrap: We have a check for an invalid Component earlier
-            self.components.get(T::ID).unwrap().borrow(), 
+            storage, 
             |idkfa| &**idkfa.downcast_ref::<T>().unwrap())
     }
     /// Get a mutable reference to `T` Component storage
@@ -71,11 +99,109 @@ impl World{
             panic!("ERROR: Tried to fetch an unregistered Component: {}", T::ID)
         }
 
+        let Ok(storage) = self.components.get(T::ID).unwrap().try_borrow_mut() else{
+            panic!("ERROR: Tried to mutably fetch Component {} while it's borrowed elsewhere", T::ID)
+        };
+
         RefMut::map(
-            self.components.get(T::ID).unwrap().borrow_mut(), 
+            storage, 
             |idkfa| &mut **idkfa.downcast_mut::<T>().unwrap())
     }
 
+    /// Get a mutable reference to `A` Component of Entity `x` and `B` Component of Entity `y` at once
+    /// 
+    /// Different Components live in different Storages, so they never alias
+    /// 
+    /// Panics if `A` and `B` are the same Component or either is not registered
+    pub fn get_two_mut<A, B>(&mut self, x: usize, y: usize) -> (Option<&mut A>, Option<&mut B>) where A: Component, B: Component{
+        if A::ID == B::ID{
+            panic!("ERROR: Tried to mutably get Component {} twice at once", A::ID)
+        }
+
+        let [Some(storage_a), Some(storage_b)] = self.components.get_disjoint_mut([A::ID, B::ID]) else{
+            panic!("ERROR: Tried to get an unregistered Component: {} or {}", A::ID, B::ID)
+        };
+
+        (
+            storage_a.get_mut().downcast_mut::<A>().unwrap().get_mut(&x),
+            storage_b.get_mut().downcast_mut::<B>().unwrap().get_mut(&y)
+        )
+    }
+    /// Get mutable references to two different resources at once
+    /// 
+    /// Every resource lives in it's own `RefCell`, so borrowing two *different* resources  
+    /// mutably is always fine, this just saves you from juggling two fetches
+    /// 
+    /// Panics if `A` and `B` are the same resource
+    pub fn fetch_res_mut2<'a, A, B>(&'a self) -> (FetchResMut<'a, A>, FetchResMut<'a, B>) where A: Resource, B: Resource{
+        if A::ID == B::ID{
+            panic!("ERROR: Tried to mutably fetch resource {} twice at once", A::ID)
+        }
+
+        (self.fetch_res_mut::<A>(), self.fetch_res_mut::<B>())
+    }
+
+    /// Get a type-erased reference to the given Entity's Component with the given ID
+    /// 
+    /// Meant for reflection, such as inspectors, that don't know the Component type.  
+    /// Downcast it with `downcast_ref` to read the Component
+    /// 
+    /// Returns None if the Component is not registered or the Entity does not have it
+    pub fn get_comp_any(&self, comp: &str, id: usize) -> Option<Ref<'_, dyn std::any::Any>>{
+        let storage = self.components.get(comp)?;
+
+        Ref::filter_map(
+            storage.borrow(), 
+            |storage| storage.get_any(id)).ok()
+    }
+    /// Get the IDs of all registered Resources, sorted
+    /// 
+    /// Meant for reflection, pair it with `resource_any` to read them
+    pub fn resource_ids(&self) -> Vec<&'static str>{
+        let mut ids: Vec<&'static str> = self.resources.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+    /// Get a type-erased reference to the Resource with the given ID
+    /// 
+    /// Meant for reflection, such as inspectors, that don't know the Resource type.  
+    /// Downcast it with `downcast_ref` to read the Resource
+    /// 
+    /// Returns None if the Resource is not registered
+    pub fn resource_any(&self, id: &str) -> Option<Ref<'_, dyn std::any::Any>>{
+        let resource = self.resources.get(id)?;
+
+        Some(Ref::map(
+            resource.borrow(), 
+            |resource| resource.as_any()))
+    }
+
+    /// Run `f` with a reference to `T` Component storage
+    /// 
+    /// The borrow only lasts for the closure, so it can't accidentally outlive it's use
+    pub fn with_comp<T, R>(&self, f: impl FnOnce(&T::STORAGE) -> R) -> R where T: Component{
+        f(&self.fetch::<T>())
+    }
+    /// Run `f` with a mutable reference to `T` Component storage
+    /// 
+    /// The borrow only lasts for the closure, so it can't accidentally outlive it's use
+    pub fn with_comp_mut<T, R>(&self, f: impl FnOnce(&mut T::STORAGE) -> R) -> R where T: Component{
+        f(&mut self.fetch_mut::<T>())
+    }
+
+    /// Query the World for `D`ata
+    /// 
+    /// Shorthand for `WorldQuery::<D>::fetch(world)`
+    pub fn query<'a, D>(&'a self) -> WorldQuery<'a, D> where D: QueryData{
+        WorldQuery::fetch(self)
+    }
+    /// Query the World for `D`ata, filtered by `F`
+    /// 
+    /// Shorthand for `WorldQuery::<D, F>::fetch(world)`
+    pub fn query_filtered<'a, D, F>(&'a self) -> WorldQuery<'a, D, F> where D: QueryData, F: QueryFilter{
+        WorldQuery::fetch(self)
+    }
+
     /// Get a reference to `T` resource
     pub fn fetch_res<'a, T>(&'a self) -> FetchRes<'a, T> where T: Resource{
         // Check if we have such Resource registered already
@@ -84,8 +210,12 @@ impl World{
             panic!("ERROR: Tried to fetch an unregistered resource: {}", T::ID)
         }
 
+        let Ok(resource) = self.resources.get(T::ID).unwrap().try_borrow() else{
+            panic!("ERROR: Tried to fetch resource {} while it's mutably borrowed elsewhere", T::ID)
+        };
+
         Ref::map(
-            self.resources.get(T::ID).unwrap().borrow(), 
+            resource, 
             |idkfa| idkfa.downcast_ref::<T>().unwrap())
     }
     /// Get a mutable reference to `T` resource
@@ -95,20 +225,47 @@ impl World{
             panic!("ERROR: Tried to fetch an unregistered resource: {}", T::ID)
         }
 
+        let Ok(resource) = self.resources.get(T::ID).unwrap().try_borrow_mut() else{
+            panic!("ERROR: Tried to mutably fetch resource {} while it's borrowed elsewhere", T::ID)
+        };
+
         RefMut::map(
-            self.resources.get(T::ID).unwrap().borrow_mut(), 
+            resource, 
             |idkfa| idkfa.downcast_mut::<T>().unwrap())
     }
+    /// Run `f` with a reference to `T` resource
+    /// 
+    /// The borrow only lasts for the closure, so it can't accidentally outlive it's use
+    pub fn with_res<T, R>(&self, f: impl FnOnce(&T) -> R) -> R where T: Resource{
+        f(&self.fetch_res::<T>())
+    }
+    /// Run `f` with a mutable reference to `T` resource
+    /// 
+    /// The borrow only lasts for the closure, so it can't accidentally outlive it's use.  
+    /// Fetching `T` again from within the closure still panics
+    pub fn with_res_mut<T, R>(&self, f: impl FnOnce(&mut T) -> R) -> R where T: Resource{
+        f(&mut self.fetch_res_mut::<T>())
+    }
 
     /// Get a reader for `T` Event
     /// 
-    /// The reader accesses the events sent in the previous frame
+    /// The reader accesses the events sent in the previous Tick
     pub fn get_event_reader<'a, T>(&'a self) -> EventReader<'a, T> where T: Event{
         self.events.get_reader()
     }
+    /// Get a reader for `T` Event that sees both the previous and the current Tick's events
+    pub fn get_event_all_reader<'a, T>(&'a self) -> AllEventReader<'a, T> where T: Event{
+        self.events.get_all_reader()
+    }
+    /// Get a consuming reader for `T` Event
+    /// 
+    /// Same as the reader, but takes the events out of the queue as it reads them
+    pub fn get_event_consumer<'a, T>(&'a self) -> ConsumingReader<'a, T> where T: Event{
+        self.events.get_consumer()
+    }
     /// Get a writer for `T` Event
     /// 
-    /// The writer sends events for the current frame
+    /// The writer sends events for the current Tick
     pub fn get_event_writer<'a, T>(&'a self) -> EventWriter<'a, T> where T: Event{
         // SAFETY: Same as above
         self.events.get_writer()
@@ -116,12 +273,46 @@ impl World{
 
     /// Get writer for System Triggers
     pub fn get_trigger_writer(&self) -> TriggerWriter<'_>{
-        TriggerWriter(self.triggers.borrow_mut())
+        TriggerWriter{
+            queue: self.triggers.borrow_mut(),
+            immediate: self.immediate_triggers.borrow_mut(),
+            payloads: self.pending_payloads.borrow_mut()
+        }
+    }
+    /// Get reader for the payloads of the Triggers firing this Tick
+    pub fn get_payload_reader(&self) -> PayloadReader<'_>{
+        PayloadReader(self.trigger_payloads.borrow_mut())
+    }
+    /// Take the oldest payload of the given Trigger firing this Tick
+    /// 
+    /// Returns None if there are no payloads left or the payload is not a `T`
+    pub fn take_trigger_payload<T: 'static>(&self, trigger: &str) -> Option<T>{
+        take_payload(&mut self.trigger_payloads.borrow_mut(), trigger)
     }
 
     /// Get writer for the Command Queue
     pub fn get_command_writer<'a>(&'a self) -> CommandWriter<'a>{
-        CommandWriter(self.commands.borrow_mut())
+        CommandWriter{
+            queue: self.commands.borrow_mut(),
+            tickets: &self.next_ticket
+        }
+    }
+    /// Get reader for the results of Ticketed Commands
+    pub fn get_result_reader(&self) -> ResultReader<'_>{
+        ResultReader(self.command_results.borrow_mut())
+    }
+    /// Take the result of a Ticketed Command out of the World
+    /// 
+    /// Returns None if the Command hasn't been applied yet, the result was already taken,  
+    /// or it wasn't taken in time and got dropped
+    /// 
+    /// Results are dropped `COMMAND_RESULT_TICKS` Ticks after they're stored
+    pub fn take_command_result<T: 'static>(&self, ticket: Ticket<T>) -> Option<T>{
+        take_result(&mut self.command_results.borrow_mut(), ticket)
+    }
+    /// Store the result of a Ticketed Command, stamped with the current Tick
+    pub(crate) fn store_command_result<T: 'static>(&mut self, ticket: u64, result: T){
+        self.command_results.get_mut().insert(ticket, (self.tick, Box::new(result)));
     }
 
     ///////////////////////////////////////////////////////////////////////////////
@@ -138,11 +329,27 @@ impl World{
             T::ID, 
             RefCell::new(Box::new(StorageContainer::<T>::new())));
     }
+    /// Defragment every Component Storage in this World
+    /// 
+    /// See `Storage::defragment`, meant to be called when a hitch is fine, like on a loading screen
+    pub fn defragment(&mut self){
+        for storage in self.components.values_mut(){
+            storage.get_mut().defragment();
+        }
+    }
+    /// Register every Component of the `S` set in this World at once
+    /// 
+    /// Takes a Tuple of Components, e.g. `world.register_bundle::<(Pos, Vel, Tag)>()`
+    pub fn register_bundle<S>(&mut self) where S: ComponentSet{
+        S::register(self);
+    }
     /// Remove the `T` Component from this World
     /// 
     /// Every Entity with this Component will have that Component dropped
     pub fn deregister_comp<T>(&mut self) where T: Component{
         self.components.remove(T::ID);
+        self.snapshotters.remove(T::ID);
+        self.serializers.remove(T::ID);
     }
 
     /// Register a `T` resource in this World
@@ -157,11 +364,54 @@ impl World{
     pub fn deregister_res<T>(&mut self) where T: Resource{
         self.resources.remove(T::ID);
     }
+    /// Move the `T` resource out of this World
+    /// 
+    /// Lets you hold onto the resource while mutating the World,
+    /// put it back with `put_res` once done
+    /// 
+    /// Returns None if the resource is not registered
+    /// 
+    /// ## WARNING
+    /// The resource is absent from the World while taken, 
+    /// any fetch for it in the meantime will panic
+    pub fn take_res<T>(&mut self) -> Option<T> where T: Resource{
+        let resource = self.resources.remove(T::ID)?.into_inner();
+
+        match resource.downcast::<T>(){
+            Ok(resource) => Some(*resource),
+            Err(_) => panic!("ERROR: Resource {} does not match the stored resource", T::ID)
+        }
+    }
+    /// Put the `T` resource back into this World
+    /// 
+    /// Unlike `register_res`, this keeps the state of the given resource
+    pub fn put_res<T>(&mut self, resource: T) where T: Resource{
+        if self.resources.contains_key(T::ID){
+            panic!("ERROR: Attempted to put back a resource that is already present: {}", T::ID)
+        }
+
+        self.resources.insert(T::ID, RefCell::new(Box::new(resource)));
+    }
+    /// Replace the `T` resource with the given value
+    /// 
+    /// Meant for swapping resources at runtime, such as reloading a config.  
+    /// Returns the old resource, or None if it wasn't registered, in which case it's registered now
+    pub fn replace_res<T>(&mut self, resource: T) -> Option<T> where T: Resource{
+        let old = self.take_res::<T>();
+        self.put_res(resource);
+        old
+    }
 
     /// Register a `T` Event in this World
     pub fn register_event<T>(&mut self) where T: Event{
         self.events.register::<T>();
     }
+    /// Register a `T` Event in this World with it's queues preallocated to hold `capacity` Events
+    /// 
+    /// Useful for high-frequency Events, like collisions, to avoid growing the queues every Tick
+    pub fn register_event_with_capacity<T>(&mut self, capacity: usize) where T: Event{
+        self.events.register_with_capacity::<T>(capacity);
+    }
     /// Remove the `T` Event from this World
     /// 
     /// The respective Read and Write queues will get removed from EventMap
@@ -169,6 +419,54 @@ impl World{
         self.events.deregister::<T>();
     }
 
+    /// Check if a Component with the given ID is registered in this World
+    pub fn has_comp(&self, id: &str) -> bool{
+        self.components.contains_key(id)
+    }
+    /// Check if a Resource with the given ID is registered in this World
+    pub fn has_res(&self, id: &str) -> bool{
+        self.resources.contains_key(id)
+    }
+    /// Check if an Event with the given ID is registered in this World
+    pub fn has_event(&self, id: &str) -> bool{
+        self.events.get_registry().contains(id)
+    }
+    /// Get the IDs of everything registered in this World, sorted
+    /// 
+    /// Registering the same ID twice as the same kind already panics,  
+    /// but the same ID used by e.g. a Component and a Resource doesn't.  
+    /// Those are flagged as conflicts, as they usually mean two plugins picked the same name
+    pub fn registration_report(&self) -> RegistrationReport{
+        let mut components: Vec<&'static str> = self.components.keys().copied().collect();
+        let mut resources: Vec<&'static str> = self.resources.keys().copied().collect();
+        let mut events: Vec<&'static str> = self.events.get_registry().iter().copied().collect();
+        components.sort_unstable();
+        resources.sort_unstable();
+        events.sort_unstable();
+
+        let mut conflicts: Vec<&'static str> = components.iter()
+            .chain(resources.iter())
+            .chain(events.iter())
+            .copied()
+            .filter(|id| 
+                [&components, &resources, &events].iter().filter(|ids| ids.binary_search(id).is_ok()).count() > 1
+            ).collect();
+        conflicts.sort_unstable();
+        conflicts.dedup();
+
+        RegistrationReport{ components, resources, events, conflicts }
+    }
+    /// Get the statistics of every Component Storage in this World, sorted by Component ID
+    /// 
+    /// Handy for spotting heavy Components sitting in a Storage that doesn't suit them
+    pub fn storage_stats(&self) -> Vec<StorageStat>{
+        let mut stats: Vec<StorageStat> = self.components.values()
+            .map(|storage| storage.borrow().stat())
+            .collect();
+        stats.sort_by_key(|stat| stat.id);
+        stats
+    }
+
     ///////////////////////////////////////////////////////////////////////////////
     // Spawn/Despawn
     ///////////////////////////////////////////////////////////////////////////////
@@ -177,9 +475,21 @@ impl World{
     /// 
     /// Returns a Builder that monitors the construction of the Entity
     pub fn spawn(&mut self) -> EntityBuilder<'_>{
+        let reused = match self.id_strategy{
+            IdStrategy::ReuseLowest => self.next_free.pop_first(),
+            IdStrategy::AlwaysIncrement => None,
+        };
+        let next_id = match reused{
+            Some(id) => id,
+            None => {
+                self.next_id += 1;
+                self.next_id - 1
+            }
+        };
+        debug_assert!(!self.entities.contains_key(&next_id), "ERROR: Spawned Entity collides with a live Entity: {}", next_id);
+
         EntityBuilder{
             entity: {
-                let next_id = self.next_free.pop_first().unwrap_or(self.entities.len());
                 self.entities.insert(next_id, Entity::new(next_id));
                 self.entities.get(&next_id).unwrap().get_token()
             },
@@ -187,19 +497,63 @@ impl World{
             components: HashSet::new()
         }
     }
+    /// Spawn a new entity at the given ID
+    /// 
+    /// Meant for keeping IDs stable, e.g. when mirroring another World.  
+    /// The Entity gets a fresh Hash, so Tokens made before don't match it, use `spawn_at_token` for that
+    /// 
+    /// Any IDs skipped over are freed for reuse by `spawn`
+    /// 
+    /// Panics if the ID is already occupied by another Entity
+    pub fn spawn_at(&mut self, id: usize) -> EntityBuilder<'_>{
+        self.spawn_at_hash(id, rand::random())
+    }
+    /// Spawn a new entity the given Token refers to
+    /// 
+    /// Meant for restoring saved Worlds, the Entity takes both the Token's ID and Hash,  
+    /// so Tokens and references between Entities stay valid
+    /// 
+    /// Any IDs skipped over are freed for reuse by `spawn`
+    /// 
+    /// Panics if the ID is already occupied by another Entity
+    pub fn spawn_at_token(&mut self, token: &Token) -> EntityBuilder<'_>{
+        self.spawn_at_hash(token.id(), token.hash())
+    }
+    /// Spawn a new entity at the given ID with the given Hash
+    pub(crate) fn spawn_at_hash(&mut self, id: usize, hash: EntityHash) -> EntityBuilder<'_>{
+        if self.entities.contains_key(&id){
+            panic!("ERROR: Attempted to spawn an Entity at an occupied ID: {}", id)
+        }
+
+        if id >= self.next_id{
+            // Free the skipped IDs, if we're reusing them at all
+            if self.id_strategy == IdStrategy::ReuseLowest{
+                self.next_free.insert_range(self.next_id..id);
+            }
+            self.next_id = id + 1;
+        }else{
+            self.next_free.remove(id);
+        }
+
+        self.entities.insert(id, Entity::with_hash(id, hash));
+
+        EntityBuilder{
+            entity: self.entities.get(&id).unwrap().get_token(),
+            world_ref: self,
+            components: HashSet::new()
+        }
+    }
     /// Despawn the given Entity
     /// 
     /// Returns `true` if the entity was found and removed, otherwise `false`
     /// 
     /// This drops all of the Entity's Components from all Storages
     pub fn despawn(&mut self, id: usize) -> bool{
-        if self.entities.remove(&id).is_some(){
-            for storage in self.components.values_mut(){
-                storage.borrow_mut().as_mut().remove(id);
-            };
-            return true
+        if !self.entities.contains_key(&id){
+            return false
         }
-        false
+        self.despawn_unchecked(&[id]);
+        true
     }
     /// Despawn the given Entity via Token
     /// 
@@ -209,23 +563,240 @@ impl World{
     /// 
     /// Note: This consumes the Token, whether valid or not. 
     /// If you're holding the Token in a struct, get a new Token
-    pub fn despawn_with_token(&mut self, mut token: Token) -> bool{
-        if !token.valid(){
+    pub fn despawn_with_token(&mut self, token: Token) -> bool{
+        if !self.is_alive(&token){
             return false
         }
+        self.despawn_unchecked(&[token.id()]);
+        true
+    }
+    /// Despawn all of the given Entities at once
+    /// 
+    /// Returns the number of Entities that were found and removed, dead and repeated IDs are skipped
+    /// 
+    /// Walks every Storage once for the whole batch instead of once per Entity,  
+    /// so prefer it over calling `despawn` in a loop for bulk cleanup
+    pub fn despawn_many(&mut self, ids: impl IntoIterator<Item = usize>) -> usize{
+        let ids: Vec<usize> = ids.into_iter()
+            .filter(|id| self.entities.contains_key(id))
+            .collect::<BTreeSet<usize>>()
+            .into_iter()
+            .collect();
+
+        self.despawn_unchecked(&ids);
+        ids.len()
+    }
+    /// Check if the Entity tracked by the Token is still alive
+    /// 
+    /// A Token is alive if it's valid and the Entity in it's slot is still the one it was made for
+    pub fn is_alive(&self, token: &Token) -> bool{
+        token.valid() 
+            && 
+        self.entities.get(&token.id()).is_some_and(|entity| token.matches(entity))
+    }
+    /// Despawn Entities that are known to exist
+    /// 
+    /// Takes every Component of the Entities out of the Storages first,  
+    /// then runs the `on_remove` hooks and sends `ComponentRemoved` Events for them,  
+    /// drops the Components and only then frees the IDs for reuse
+    fn despawn_unchecked(&mut self, ids: &[usize]){
+        let mut removed = Vec::new();
+
+        for storage in self.components.values_mut(){
+            let storage = storage.get_mut();
+            for id in ids.iter(){
+                if let Some(comp) = storage.take_removed(*id){
+                    removed.push((*id, storage.comp_id(), comp));
+                }
+            }
+        }
+
+        // No Storage is borrowed anymore, so the hooks are free to fetch anything
+        for (entity, _, comp) in removed.iter_mut(){
+            comp.on_remove(*entity, self);
+        }
 
-        if let Some(entity) = self.entities.get(&token.id()){
-            if !token.validate(entity){
-                return false
+        {
+            let mut writer = self.get_event_writer::<ComponentRemoved>();
+            for (entity, comp, _) in removed{
+                writer.send(ComponentRemoved{ entity, comp });
             }
-            
-            self.entities.remove(&token.id());
-            for storage in self.components.values_mut(){
-                storage.borrow_mut().as_mut().remove(token.id());
+        }
+
+        for id in ids.iter(){
+            self.entities.remove(id);
+            if self.id_strategy == IdStrategy::ReuseLowest{
+                self.next_free.insert(*id);
+            }
+        }
+    }
+    /// Add a `T` Component to an existing Entity, replacing the previous one if present
+    /// 
+    /// Panics if the Component is not registered or the Entity doesn't exist
+    pub fn insert_comp<T>(&mut self, id: usize, comp: T) where T: Component{
+        if !self.entities.contains_key(&id){
+            panic!("ERROR: Tried to insert Component {} into a nonexistent Entity: {}", T::ID, id)
+        }
+
+        self.fetch_mut::<T>().insert(id, comp);
+    }
+    /// Same as `insert_comp`, but registers the `T` Component first if it isn't already
+    /// 
+    /// Handy while prototyping, stick to `insert_comp` once the registrations settle
+    pub fn insert_comp_or_register<T>(&mut self, id: usize, comp: T) where T: Component{
+        if !self.components.contains_key(T::ID){
+            self.register_comp::<T>();
+        }
+
+        self.insert_comp(id, comp);
+    }
+    /// Set how this World hands out IDs to new Entities
+    /// 
+    /// Switching to `AlwaysIncrement` keeps the already freed IDs around,  
+    /// they just won't be handed out until switching back
+    pub fn set_id_strategy(&mut self, strategy: IdStrategy){
+        self.id_strategy = strategy;
+    }
+
+    ///////////////////////////////////////////////////////////////////////////////
+    // Snapshots
+    ///////////////////////////////////////////////////////////////////////////////
+
+    /// Mark the `T` Component to be captured by World snapshots
+    /// 
+    /// Panics if the Component is not registered
+    pub fn register_snapshot<T>(&mut self) where T: SnapshotableComponent{
+        if !self.components.contains_key(T::ID){
+            panic!("ERROR: Attempted to snapshot an unregistered Component: {}", T::ID)
+        }
+
+        self.snapshotters.insert(T::ID, |storage| Box::new(storage.downcast_ref::<T>().unwrap().clone()));
+    }
+    /// Capture the current state of Entities and snapshotable Components
+    /// 
+    /// Only Components marked via `register_snapshot` are captured,  
+    /// the rest of the Components, as well as Resources and Events, are skipped
+    pub fn snapshot(&self) -> WorldSnapshot{
+        WorldSnapshot{
+            entities: self.entities.clone(),
+            next_free: self.next_free.clone(),
+            next_id: self.next_id,
+            components: self.snapshotters.iter()
+                .map(|(id, snapshotter)| (*id, snapshotter(&**self.components[id].borrow())))
+                .collect()
+        }
+    }
+    /// Restore the Entities and snapshotable Components from a snapshot
+    /// 
+    /// Components that weren't captured keep their current values,  
+    /// except for those of Entities that didn't exist at the time of the snapshot, which are dropped.  
+    /// No removal hooks or Events are run for them
+    pub fn restore(&mut self, snapshot: WorldSnapshot){
+        // Drop uncaptured Components of Entities that are about to disappear
+        for id in self.entities.keys(){
+            if snapshot.entities.contains_key(id){
+                continue
+            }
+            for (comp_id, storage) in self.components.iter_mut(){
+                if !snapshot.components.contains_key(comp_id){
+                    storage.get_mut().remove(*id);
+                }
+            }
+        }
+
+        self.entities = snapshot.entities;
+        self.next_free = snapshot.next_free;
+        self.next_id = snapshot.next_id;
+
+        for (id, storage) in snapshot.components{
+            self.components.insert(id, RefCell::new(storage));
+        }
+    }
+    /// Copy out every `T` Component alongside it's Entity ID, in ascending ID order
+    /// 
+    /// A single-Component counterpart to `snapshot`, meant as a building block for replication.  
+    /// Unlike `snapshot`, the Component doesn't need to be marked beforehand
+    /// 
+    /// Needs the Component's Storage to implement `IterStorage`
+    pub fn export_comp<T>(&self) -> Vec<(usize, T)> where T: Component<STORAGE: IterStorage<T>> + Clone{
+        self.fetch::<T>().iter_ordered()
+            .map(|(id, comp)| (id, comp.clone()))
+            .collect()
+    }
+    /// Replace the whole `T` Storage's contents with the given Components
+    /// 
+    /// Components of Entities that don't exist are skipped.  
+    /// No removal hooks or Events are run for the replaced Components
+    pub fn import_comp<T>(&mut self, data: Vec<(usize, T)>) where T: Component{
+        let mut storage = self.fetch_mut::<T>();
+
+        for id in self.entities.keys(){
+            storage.remove(id);
+        }
+
+        for (id, comp) in data{
+            if self.entities.contains_key(&id){
+                storage.insert(id, comp);
+            }
+        }
+    }
+
+    ///////////////////////////////////////////////////////////////////////////////
+    // Scenes
+    ///////////////////////////////////////////////////////////////////////////////
+
+    /// Mark the `T` Component to be saved in Scenes
+    /// 
+    /// Panics if the Component is not registered
+    pub fn register_serializable<T>(&mut self) where T: SerializableComponent{
+        if !self.components.contains_key(T::ID){
+            panic!("ERROR: Attempted to serialize an unregistered Component: {}", T::ID)
+        }
+
+        self.serializers.insert(T::ID, Serializer{
+            serialize: |comp| comp.downcast_ref::<T>().unwrap().serialize(),
+            deserialize: |bytes| T::deserialize(bytes).map(|comp| Box::new(comp) as Box<dyn std::any::Any>)
+        });
+    }
+    /// Save every Entity along with it's serializable Components
+    /// 
+    /// Only Components marked via `register_serializable` are saved,  
+    /// Entities without any are still saved to keep their IDs and Hashes
+    pub fn serialize_scene(&self) -> Scene{
+        let entities = self.entities.iter()
+            .map(|(id, entity)| SceneEntity{
+                id: *id,
+                hash: entity.hash(),
+                components: self.serializers.iter()
+                    .filter_map(|(comp_id, serializer)| 
+                        self.get_comp_any(comp_id, *id).map(|comp| (comp_id.to_string(), (serializer.serialize)(&*comp)))
+                    ).collect()
+            }).collect();
+
+        Scene{ entities }
+    }
+    /// Rebuild the Entities of a Scene in this World, keeping their IDs and Hashes
+    /// 
+    /// Tokens made before saving stay valid for the rebuilt Entities
+    /// 
+    /// Panics if an ID is already occupied, a Component is not marked as serializable  
+    /// or it's bytes are not valid
+    pub fn deserialize_scene(&mut self, scene: &Scene){
+        for entity in scene.entities.iter(){
+            self.spawn_at_hash(entity.id, entity.hash).finish();
+
+            for (comp_id, bytes) in entity.components.iter(){
+                let Some(serializer) = self.serializers.get(comp_id.as_str()) else{
+                    panic!("ERROR: Attempted to deserialize a Component that is not serializable: {}", comp_id)
+                };
+                let Some(comp) = (serializer.deserialize)(bytes) else{
+                    panic!("ERROR: Failed to deserialize Component {} of Entity {}", comp_id, entity.id)
+                };
+
+                // Unwrap: Serializers only exist for registered Components, and make the right type
+                self.components.get_mut(comp_id.as_str()).unwrap().get_mut().insert_any(entity.id, comp).ok().unwrap();
             }
-            return true
         }
-        false
     }
 
     ///////////////////////////////////////////////////////////////////////////////
@@ -236,13 +807,40 @@ impl World{
     pub(crate) fn swap_event_buffers(&mut self){
         self.events.swap_buffers();
     }
+    /// End the current Tick
+    /// 
+    /// Swaps the Event buffers, so the Events sent this Tick become readable on the next one,  
+    /// and drops the Ticketed Command results nobody took in time.  
+    /// The Dispatcher does this at the end of every Logic Tick,  
+    /// only call it yourself if you're running the World in your own loop
+    pub fn end_tick(&mut self){
+        self.swap_event_buffers();
+        self.tick += 1;
+
+        let tick = self.tick;
+        self.command_results.get_mut().retain(|_, (stored, _)| tick - *stored <= COMMAND_RESULT_TICKS);
+    }
+    /// Get the number of Ticks that have ended so far
+    /// 
+    /// Kept by the World itself, so it works even without `DeltaT` registered
+    pub fn current_tick(&self) -> u64{
+        self.tick
+    }
 
     /// Take the Trigger queue
     /// 
-    /// This will initialize a new queue in it's place
+    /// This will initialize a new queue in it's place.  
+    /// The payloads sent with the Triggers become readable, replacing the previous ones
     pub(crate) fn take_triggers(&mut self) -> Vec<&'static str>{
+        *self.trigger_payloads.get_mut() = self.pending_payloads.take();
         self.triggers.take()
     }
+    /// Take the Immediate Trigger queue
+    /// 
+    /// This will initialize a new queue in it's place
+    pub(crate) fn take_immediate_triggers(&mut self) -> Vec<&'static str>{
+        self.immediate_triggers.take()
+    }
     /// Take the full Command queue
     /// 
     /// This will initialize a new queue in it's place
@@ -262,6 +860,63 @@ impl World{
     }
 }
 
+/// Clones a Component's Storage for a snapshot
+type Snapshotter = fn(&(dyn StorageWrapper + 'static)) -> Box<dyn StorageWrapper>;
+
+/// # World Snapshot
+/// A copy of the World's Entities and snapshotable Components at some point in time
+/// 
+/// Made via `World::snapshot` and applied back via `World::restore`
+pub struct WorldSnapshot{
+    entities: BTreeMap<usize, Entity>,
+    next_free: FreeIds,
+    next_id: usize,
+    components: HashMap<&'static str, Box<dyn StorageWrapper>>
+}
+
+/// # Registration Report
+/// Everything registered in a World by ID, made via `World::registration_report`
+/// 
+/// `conflicts` lists the IDs that are registered as more than one kind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistrationReport{
+    pub components: Vec<&'static str>,
+    pub resources: Vec<&'static str>,
+    pub events: Vec<&'static str>,
+    pub conflicts: Vec<&'static str>
+}
+
+/// Turns a Component into bytes and back for a Scene
+struct Serializer{
+    serialize: fn(&dyn std::any::Any) -> Vec<u8>,
+    deserialize: fn(&[u8]) -> Option<Box<dyn std::any::Any>>
+}
+
+/// # Scene
+/// A portable copy of the World's Entities and serializable Components
+/// 
+/// Made via `World::serialize_scene` and rebuilt via `World::deserialize_scene`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Scene{
+    pub entities: Vec<SceneEntity>
+}
+/// # Scene Entity
+/// A single Entity of a Scene, with it's serialized Components keyed by Component ID
+/// 
+/// `hash` is the Entity's Hash, kept so Tokens to it stay valid
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SceneEntity{
+    pub id: usize,
+    pub hash: u32,
+    pub components: BTreeMap<String, Vec<u8>>
+}
+
+impl Default for World{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests{
     use super::*;
@@ -301,6 +956,26 @@ mod tests{
             world.fetch_mut::<idkfa>();
         }
         #[test]
+        fn with_comp(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.spawn().with(idkfa(5)).finish();
+
+            world.with_comp_mut::<idkfa, _>(|storage| storage.get_mut(&0).unwrap().0 = 10);
+
+            // The borrow is released, so this doesn't panic
+            drop(world.fetch_mut::<idkfa>());
+
+            assert!(world.with_comp::<idkfa, _>(|storage| storage.get(&0).unwrap().0) == 10);
+        }
+        #[test]
         fn fetch_res(){
             struct idkfa;
             impl Resource for idkfa{
@@ -319,6 +994,39 @@ mod tests{
             world.fetch_res_mut::<idkfa>();
         }
         #[test]
+        fn resource_any(){
+            struct idkfa(u8);
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self(5)
+                }
+            }
+            struct iddqd(u16);
+            impl Resource for iddqd{
+                const ID: &'static str = "iddqd";
+            
+                fn new() -> Self {
+                    Self(10)
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+            world.register_res::<iddqd>();
+
+            let ids = world.resource_ids();
+            assert!(ids.contains(&"idkfa") && ids.contains(&"iddqd"));
+            assert!(ids.is_sorted());
+
+            assert!(world.resource_any("idkfa").unwrap().downcast_ref::<idkfa>().unwrap().0 == 5);
+            assert!(world.resource_any("iddqd").unwrap().downcast_ref::<iddqd>().unwrap().0 == 10);
+            // Wrong type
+            assert!(world.resource_any("iddqd").unwrap().downcast_ref::<idkfa>().is_none());
+            assert!(world.resource_any("idclip").is_none());
+        }
+        #[test]
         #[should_panic]
         fn fetch_res_invalid(){
             struct idkfa;
@@ -351,32 +1059,929 @@ mod tests{
             world.get_event_writer::<idkfa>();
         }
         #[test]
-        #[should_panic]
-        fn fetch_event_invalid(){
-            struct idkfa;
-            impl Event for idkfa{
+        fn query(){
+            struct idkfa(u8);
+            struct iddqd(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
                 const ID: &'static str = "idkfa";
             }
+            impl Component for iddqd{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "iddqd";
+            }
 
-            let world = World::new();
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(idkfa(1)).with(iddqd(10)).finish();
+            world.spawn().with(idkfa(2)).finish();
+
+            for (kfa, dqd) in world.query::<(&idkfa, &mut iddqd)>().iter_mut(){
+                dqd.0 += kfa.0;
+            }
+            assert!(world.fetch::<iddqd>().get(&0).unwrap().0 == 11);
+
+            let query = world.query_filtered::<&idkfa, Without<iddqd>>();
+            assert!(query.iter().map(|kfa| kfa.0).collect::<Vec<_>>() == [2]);
+        }
+        #[test]
+        fn event_read_ordered(){
+            struct idkfa(u8);
+            struct iddqd;
+            impl Event for idkfa{
+                const ID: &'static str = "idkfa";
+            }
+            impl Event for iddqd{
+                const ID: &'static str = "iddqd";
+            }
+
+            fn run() -> Vec<(EventStamp, u8)>{
+                let mut world = World::new();
+                world.register_event::<idkfa>();
+                world.register_event::<iddqd>();
+
+                world.end_tick();
+
+                // Two writers taking turns within the same Tick
+                world.get_event_writer::<idkfa>().send(idkfa(0));
+                world.get_event_writer::<iddqd>().send(iddqd);
+                world.get_event_writer::<idkfa>().send(idkfa(1));
+                world.get_event_writer::<idkfa>().send(idkfa(2));
+
+                world.end_tick();
+
+                world.get_event_reader::<idkfa>().read_ordered().map(|(stamp, event)| (stamp, event.0)).collect()
+            }
+
+            let events = run();
+            assert!(events.iter().map(|(_, id)| *id).collect::<Vec<_>>() == [0, 1, 2]);
+            assert!(events.iter().map(|(stamp, _)| stamp.sequence).collect::<Vec<_>>() == [0, 2, 3]);
+            assert!(events.iter().all(|(stamp, _)| stamp.tick == 1));
+
+            assert!(run() == events);
+        }
+        #[test]
+        fn event_end_tick(){
+            struct idkfa;
+            impl Event for idkfa{
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_event::<idkfa>();
+
+            world.get_event_writer::<idkfa>().send(idkfa);
+            assert!(world.get_event_reader::<idkfa>().event_count() == 0);
+
+            world.end_tick();
+            assert!(world.get_event_reader::<idkfa>().event_count() == 1);
+
+            world.end_tick();
+            assert!(world.get_event_reader::<idkfa>().event_count() == 0);
+        }
+        #[test]
+        #[should_panic]
+        fn fetch_event_invalid(){
+            struct idkfa;
+            impl Event for idkfa{
+                const ID: &'static str = "idkfa";
+            }
+
+            let world = World::new();
 
             // SHOULD PANIC
             world.get_event_reader::<idkfa>();
             world.get_event_writer::<idkfa>();
         }
-    }
-    mod test_registers{
-        use super::*;
         #[test]
-        fn register_comp(){
+        fn replace_res(){
+            struct idkfa(usize);
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
+            let mut world = World::new();
+            assert!(world.replace_res(idkfa(5)).is_none());
+            assert!(world.fetch_res::<idkfa>().0 == 5);
+
+            world.fetch_res_mut::<idkfa>().0 = 10;
+
+            assert!(world.replace_res(idkfa(20)).is_some_and(|old| old.0 == 10));
+            assert!(world.fetch_res::<idkfa>().0 == 20);
+        }
+        #[test]
+        fn take_res(){
+            struct idkfa(usize);
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+
+            let mut res = world.take_res::<idkfa>().unwrap();
+            assert!(!world.has_res(idkfa::ID));
+            assert!(world.take_res::<idkfa>().is_none());
+
+            // Mutate the World while holding the resource
+            world.spawn().finish();
+            res.0 = world.get_entities().len();
+
+            world.put_res(res);
+            assert!(world.fetch_res::<idkfa>().0 == 1);
+        }
+        #[test]
+        fn fetch_res_mut2(){
+            struct idkfa(u8);
+            struct iddqd(u8);
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+            impl Resource for iddqd{
+                const ID: &'static str = "iddqd";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+            world.register_res::<iddqd>();
+
+            {
+                let (mut kfa, mut dqd) = world.fetch_res_mut2::<idkfa, iddqd>();
+                kfa.0 = 5;
+                dqd.0 = kfa.0 * 2;
+            }
+
+            assert!(world.fetch_res::<idkfa>().0 == 5);
+            assert!(world.fetch_res::<iddqd>().0 == 10);
+        }
+        #[test]
+        fn with_res(){
+            struct idkfa(u8);
+            struct iddqd(u8);
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self(5)
+                }
+            }
+            impl Resource for iddqd{
+                const ID: &'static str = "iddqd";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+            world.register_res::<iddqd>();
+
+            // Different Resources nest just fine
+            world.with_res::<idkfa, _>(|kfa| 
+                world.with_res_mut::<iddqd, _>(|dqd| dqd.0 = kfa.0 * 2)
+            );
+
+            // The borrows are released, so this doesn't panic
+            drop(world.fetch_res_mut::<idkfa>());
+
+            assert!(world.with_res::<iddqd, _>(|dqd| dqd.0) == 10);
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: Tried to fetch resource idkfa while it's mutably borrowed elsewhere")]
+        fn with_res_reentrant(){
+            struct idkfa(u8);
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+
+            world.with_res_mut::<idkfa, _>(|kfa| {
+                // SHOULD PANIC
+                kfa.0 = world.with_res::<idkfa, _>(|kfa| kfa.0) + 1;
+            });
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: Tried to mutably fetch resource idkfa twice at once")]
+        fn fetch_res_mut2_same(){
+            struct idkfa;
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+
+            // SHOULD PANIC
+            world.fetch_res_mut2::<idkfa, idkfa>();
+        }
+        #[test]
+        fn get_two_mut(){
+            struct idkfa(u8);
+            struct iddqd(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+            impl Component for iddqd{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "iddqd";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(idkfa(1)).finish();
+            world.spawn().with(iddqd(2)).finish();
+
+            {
+                let (kfa, dqd) = world.get_two_mut::<idkfa, iddqd>(0, 1);
+                let (kfa, dqd) = (kfa.unwrap(), dqd.unwrap());
+                std::mem::swap(&mut kfa.0, &mut dqd.0);
+            }
+
+            assert!(world.fetch::<idkfa>().get(&0).unwrap().0 == 2);
+            assert!(world.fetch::<iddqd>().get(&1).unwrap().0 == 1);
+
+            let (kfa, dqd) = world.get_two_mut::<idkfa, iddqd>(1, 0);
+            assert!(kfa.is_none() && dqd.is_none());
+        }
+        #[test]
+        #[should_panic(expected = "ERROR: Tried to mutably get Component idkfa twice at once")]
+        fn get_two_mut_same(){
+            struct idkfa;
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            // SHOULD PANIC
+            world.get_two_mut::<idkfa, idkfa>(0, 1);
+        }
+        #[test]
+        fn fetch_comp_any(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.spawn().with(idkfa(5)).finish();
+
+            assert!(world.get_comp_any(idkfa::ID, 0).unwrap().downcast_ref::<idkfa>().unwrap().0 == 5);
+            assert!(world.get_comp_any(idkfa::ID, 1).is_none());
+            assert!(world.get_comp_any("iddqd", 0).is_none());
+        }
+        #[test]
+        #[should_panic]
+        fn put_res_collision(){
+            struct idkfa;
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+
+            // SHOULD PANIC
+            world.put_res(idkfa);
+        }
+    }
+    mod test_registers{
+        use super::*;
+        #[test]
+        fn register_comp(){
+            struct idkfa;
+            struct iddqd;
+
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+            impl Component for iddqd{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "iddqd";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            // Check registries
+            assert!(world.components.contains_key(idkfa::ID));
+            assert!(world.components.contains_key(iddqd::ID));
+
+            world.deregister_comp::<idkfa>();
+            world.deregister_comp::<iddqd>();
+
+            // Check deregistries
+            assert!(!world.components.contains_key(idkfa::ID));
+            assert!(!world.components.contains_key(iddqd::ID));
+            
+        }
+        #[test]
+        #[should_panic]
+        fn register_comp_collision(){
+            struct idkfa;
+            struct iddqd;
+
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+            impl Component for iddqd{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+        }
+        #[test]
+        fn register_default_res(){
+            #[derive(Default)]
+            struct idkfa{
+                value: u8,
+                name: String
+            }
+            impl DefaultResource for idkfa{
+                const RES_ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+
+            assert!(world.has_res("idkfa"));
+            let res = world.fetch_res::<idkfa>();
+            assert!(res.value == 0 && res.name.is_empty());
+            assert!(idkfa::ID == "idkfa");
+        }
+        #[test]
+        fn register_commands(){
+            struct idkfa;
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+            struct iddqd(u8);
+            impl Resource for iddqd{
+                const ID: &'static str = "iddqd";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
+            let mut world = World::new();
+
+            {
+                let mut commands = world.get_command_writer();
+                commands.send(RegisterComponent::<idkfa>::new());
+                commands.send(RegisterResource::<iddqd>::new());
+                // Already registered by then, shouldn't panic
+                commands.send(RegisterComponent::<idkfa>::new());
+                commands.send(RegisterResource::<iddqd>::new());
+            }
+            assert!(!world.has_comp(idkfa::ID));
+
+            for mut command in world.take_commands(){
+                command.execute(&mut world);
+            }
+
+            assert!(world.has_comp(idkfa::ID));
+            assert!(world.has_res(iddqd::ID));
+            world.spawn().with(idkfa).finish();
+            assert!(world.fetch::<idkfa>().len() == 1);
+        }
+        #[test]
+        fn registration_report(){
+            struct idkfa;
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+            // Another plugin picked the same ID
+            struct OtherIdkfa;
+            impl Resource for OtherIdkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+            struct iddqd;
+            impl Event for iddqd{
+                const ID: &'static str = "iddqd";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_res::<OtherIdkfa>();
+            world.register_event::<iddqd>();
+
+            let report = world.registration_report();
+
+            assert!(report.components == ["idkfa"]);
+            assert!(report.resources.contains(&"idkfa") && report.resources.contains(&DeltaT::ID));
+            assert!(report.events.contains(&"iddqd"));
+            assert!(report.conflicts == ["idkfa"]);
+        }
+        #[test]
+        fn register_bundle(){
+            struct idkfa;
+            struct iddqd;
+            struct idclip;
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+            impl Component for iddqd{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "iddqd";
+            }
+            impl Component for idclip{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idclip";
+            }
+
+            let mut world = World::new();
+            world.register_bundle::<(idkfa, iddqd, idclip)>();
+
+            assert!(world.components.contains_key(idkfa::ID));
+            assert!(world.components.contains_key(iddqd::ID));
+            assert!(world.components.contains_key(idclip::ID));
+        }
+        #[test]
+        fn register_res(){
+            struct idkfa;
+            struct iddqd;
+
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+            impl Resource for iddqd{
+                const ID: &'static str = "iddqd";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+
+            let mut world = World::new();
+
+            world.register_res::<idkfa>();
+            world.register_res::<iddqd>();
+
+            // Check registries
+            assert!(world.resources.contains_key(idkfa::ID));
+            assert!(world.resources.contains_key(iddqd::ID));
+
+
+            world.deregister_res::<idkfa>();
+            world.deregister_res::<iddqd>();
+
+            // Check deregistries
+            assert!(!world.resources.contains_key(idkfa::ID));
+            assert!(!world.resources.contains_key(iddqd::ID));
+        }
+        #[test]
+        #[should_panic]
+        fn register_res_collision(){
+            struct idkfa;
+            struct iddqd;
+
+            impl Resource for idkfa{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+            impl Resource for iddqd{
+                const ID: &'static str = "idkfa";
+            
+                fn new() -> Self {
+                    Self
+                }
+            }
+
+            let mut world = World::new();
+            world.register_res::<idkfa>();
+            world.register_res::<iddqd>();
+        }
+        #[test]
+        fn register_event(){
+            struct idkfa;
+            struct iddqd;
+
+            impl Event for idkfa{
+                const ID: &'static str = "idkfa";
+            }
+            impl Event for iddqd{
+                const ID: &'static str = "iddqd";
+            }
+
+            let mut world = World::new();
+
+            world.register_event::<idkfa>();
+            world.register_event::<iddqd>();
+            // Check registries
+            assert!(world.events.get_registry().contains(idkfa::ID));
+            assert!(world.events.get_registry().contains(iddqd::ID));
+
+            world.deregister_event::<idkfa>();
+            world.deregister_event::<iddqd>();
+            // Check deregistries
+            assert!(!world.events.get_registry().contains(idkfa::ID));
+            assert!(!world.events.get_registry().contains(iddqd::ID));
+        }
+        #[test]
+        #[should_panic]
+        fn register_event_collision(){
+            struct idkfa;
+            struct iddqd;
+
+            impl Event for idkfa{
+                const ID: &'static str = "idkfa";
+            }
+            impl Event for iddqd{
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_event::<idkfa>();
+            world.register_event::<iddqd>();
+        }
+    }
+    mod test_spawns{
+        use super::*;
+
+        #[test]
+        fn spawn(){
+            let mut world = World::new();
+
+            let _ = world.spawn();
+
+            assert!(world.entities.len() == 1);
+        }
+        #[test]
+        fn spawn_build(){
+            struct idkfa;
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            world.spawn().with(idkfa).finish();
+
+            assert!(world.entities.len() == 1);
+            assert!(world.fetch::<idkfa>().get(&0).is_some())
+        }
+        #[test]
+        fn spawn_bundle(){
+            struct idkfa(u8);
+            struct iddqd(u8);
+            struct idclip(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+            impl Component for iddqd{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "iddqd";
+            }
+            impl Component for idclip{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idclip";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+            world.register_comp::<idclip>();
+
+            let builder = world.spawn().with_bundle((idkfa(1), iddqd(2), idclip(3)));
+            assert!(builder.components().len() == 3);
+            builder.finish();
+
+            assert!(world.fetch::<idkfa>().get(&0).unwrap().0 == 1);
+            assert!(world.fetch::<iddqd>().get(&0).unwrap().0 == 2);
+            assert!(world.fetch::<idclip>().get(&0).unwrap().0 == 3);
+        }
+        #[test]
+        fn spawn_no_collision(){
+            let mut world = World::new();
+
+            let first = world.spawn().finish();
+            world.spawn().finish();
+            world.spawn().finish();
+
+            assert!(world.despawn(1));
+            assert!(world.despawn(2));
+
+            // `len()` is 1 here, which is the live Entity 0
+            let reused = world.spawn().finish();
+            assert!(reused.id() == 1);
+            assert!(world.is_alive(&first));
+
+            assert!(world.spawn().finish().id() == 2);
+            assert!(world.spawn().finish().id() == 3);
+            assert!(world.entities.len() == 4);
+        }
+        #[test]
+        fn spawn_always_increment(){
+            let mut world = World::new();
+            world.set_id_strategy(IdStrategy::AlwaysIncrement);
+
+            for id in 0..3{
+                assert!(world.spawn().finish().id() == id);
+            }
+            assert!(world.despawn(0));
+
+            // The freed ID is not reused, and neither is the `len()` of 2
+            assert!(world.spawn().finish().id() == 3);
+            assert!(world.entities.len() == 3);
+            assert!(world.next_free.is_empty());
+        }
+        #[test]
+        fn spawn_at(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            let token = world.spawn_at(42).with(idkfa(5)).finish();
+            assert!(token.id() == 42);
+
+            // Skipped IDs get filled first
+            assert!(world.spawn().finish().id() == 0);
+            assert!(world.spawn().finish().id() == 1);
+            assert!(world.despawn(0));
+            assert!(world.despawn(1));
+
+            assert!(world.is_alive(&token));
+            assert!(world.fetch::<idkfa>().get(&42).unwrap().0 == 5);
+
+            // Filling up every gap moves on past the spawned Entity
+            for id in 0..42{
+                assert!(world.spawn().finish().id() == id);
+            }
+            assert!(world.spawn().finish().id() == 43);
+        }
+        #[test]
+        fn spawn_at_token(){
+            let mut old = World::new();
+            old.spawn().finish();
+            let token = old.spawn().finish();
+
+            let mut world = World::new();
+            world.spawn_at_token(&token).finish();
+            assert!(world.is_alive(&token));
+
+            // A plain `spawn_at` rolls a new Hash
+            let mut world = World::new();
+            world.spawn_at(token.id()).finish();
+            assert!(!world.is_alive(&token));
+        }
+        #[test]
+        fn spawn_at_far(){
+            let mut world = World::new();
+
+            // The skipped IDs are kept as a range, not one by one
+            world.spawn_at(1 << 40).finish();
+            assert!(world.next_free.ranges.len() == 1);
+
+            assert!(world.spawn().finish().id() == 0);
+            world.spawn_at(5).finish();
+            assert!(world.next_free.ranges.len() == 2);
+            assert!(!world.next_free.contains(&5));
+            assert!(world.next_free.contains(&6));
+
+            assert!(world.despawn(0));
+            assert!(world.despawn(5));
+            assert!(world.next_free.ranges.len() == 1);
+            assert!(world.spawn().finish().id() == 0);
+        }
+        #[test]
+        #[should_panic]
+        fn spawn_at_occupied(){
+            let mut world = World::new();
+
+            world.spawn().finish();
+
+            // SHOULD PANIC
+            world.spawn_at(0).finish();
+        }
+        #[test]
+        fn spawn_finish_token(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            world.spawn().with(idkfa(0)).finish();
+            let mut token = world.spawn().with(idkfa(5)).finish();
+
+            let query: WorldQuery<&idkfa> = WorldQuery::fetch(&world);
+            assert!(token.id() == 1);
+            assert!(query.get_from_token(&mut token).unwrap().0 == 5);
+        }
+        #[test]
+        fn despawn_id(){
+            struct idkfa;
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            let _ = world.spawn().with(idkfa);
+
+            assert!(world.despawn(0));
+            assert!(world.fetch::<idkfa>().get(&0).is_none());
+        }
+        #[test]
+        fn commands_stale_token(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            let stale = world.spawn().finish();
+            world.despawn(stale.id());
+            // Takes the stale Token's ID
+            let fresh = world.spawn().with(idkfa(1)).finish();
+            assert!(fresh.id() == stale.id());
+
+            {
+                let mut commands = world.get_command_writer();
+                commands.entity(&stale).insert(idkfa(5));
+                commands.entity(&stale).despawn();
+            }
+            for mut command in world.take_commands(){
+                command.execute(&mut world);
+            }
+
+            assert!(world.is_alive(&fresh));
+            assert!(world.fetch::<idkfa>().get(&fresh.id()).unwrap().0 == 1);
+        }
+        #[test]
+        fn spawn_ticketed(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.spawn().with(idkfa(0)).finish();
+
+            let (first, second) = {
+                let mut commands = world.get_command_writer();
+                (commands.spawn_with_ticket((idkfa(5),)), commands.spawn_with_ticket((idkfa(10),)))
+            };
+
+            // Not applied yet
+            assert!(world.take_command_result(first).is_none());
+
+            for mut command in world.take_commands(){
+                command.execute(&mut world);
+            }
+            world.end_tick();
+
+            let first = world.take_command_result(first).unwrap();
+            let second = world.take_command_result(second).unwrap();
+            assert!(world.fetch::<idkfa>().get(&first).unwrap().0 == 5);
+            assert!(world.fetch::<idkfa>().get(&second).unwrap().0 == 10);
+            assert!(first != second);
+
+            // Taken already
+            assert!(world.command_results.borrow().is_empty());
+        }
+        #[test]
+        fn ticketed_expire(){
+            struct idkfa;
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "idkfa";
+            }
+
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+
+            let (first, second) = {
+                let mut commands = world.get_command_writer();
+                (commands.spawn_with_ticket((idkfa,)), commands.spawn_with_ticket((idkfa,)))
+            };
+            for mut command in world.take_commands(){
+                command.execute(&mut world);
+            }
+
+            for _ in 0..COMMAND_RESULT_TICKS{
+                world.end_tick();
+            }
+            assert!(world.take_command_result(first).is_some());
+
+            // One Tick too late
+            world.end_tick();
+            assert!(world.take_command_result(second).is_none());
+            assert!(world.command_results.borrow().is_empty());
+        }
+        #[test]
+        fn despawn_many(){
             struct idkfa;
-            struct iddqd;
-
             impl Component for idkfa{
                 type STORAGE = TestStorage<Self>;
             
                 const ID: &'static str = "idkfa";
             }
+            struct iddqd;
             impl Component for iddqd{
                 type STORAGE = TestStorage<Self>;
             
@@ -387,208 +1992,464 @@ mod tests{
             world.register_comp::<idkfa>();
             world.register_comp::<iddqd>();
 
-            // Check registries
-            assert!(world.components.contains_key(idkfa::ID));
-            assert!(world.components.contains_key(iddqd::ID));
+            for id in 0..200{
+                let entity = world.spawn().with(idkfa);
+                if id % 4 == 0{
+                    entity.with(iddqd);
+                }
+            }
 
-            world.deregister_comp::<idkfa>();
-            world.deregister_comp::<iddqd>();
+            // Every odd Entity, plus some that are dead or repeated
+            let despawned = world.despawn_many((0..200).filter(|id| id % 2 == 1).chain([1, 3, 500]));
+            assert!(despawned == 100);
 
-            // Check deregistries
-            assert!(!world.components.contains_key(idkfa::ID));
-            assert!(!world.components.contains_key(iddqd::ID));
-            
+            for id in 0..200{
+                assert!(world.entities.contains_key(&id) == (id % 2 == 0));
+            }
+            assert!(world.fetch::<idkfa>().len() == 100);
+            assert!(world.fetch::<iddqd>().len() == 50);
+
+            // One per removed Component
+            world.end_tick();
+            assert!(world.get_event_reader::<ComponentRemoved>().event_count() == 100);
         }
         #[test]
-        #[should_panic]
-        fn register_comp_collision(){
+        fn despawn_token(){
             struct idkfa;
-            struct iddqd;
-
             impl Component for idkfa{
                 type STORAGE = TestStorage<Self>;
             
                 const ID: &'static str = "idkfa";
             }
-            impl Component for iddqd{
-                type STORAGE = TestStorage<Self>;
-            
-                const ID: &'static str = "idkfa";
-            }
 
             let mut world = World::new();
             world.register_comp::<idkfa>();
-            world.register_comp::<iddqd>();
-        }
-        #[test]
-        fn register_res(){
-            struct idkfa;
-            struct iddqd;
 
-            impl Resource for idkfa{
-                const ID: &'static str = "idkfa";
-            
-                fn new() -> Self {
-                    Self
-                }
-            }
-            impl Resource for iddqd{
-                const ID: &'static str = "iddqd";
-            
-                fn new() -> Self {
-                    Self
-                }
-            }
+            let token = world.spawn().with(idkfa).get_token();
 
+            assert!(world.despawn_with_token(token.clone()));
+            assert!(world.fetch::<idkfa>().get_from_token(&token.clone()).is_none());
+        }
+        #[test]
+        fn despawn_token_reused(){
             let mut world = World::new();
 
-            world.register_res::<idkfa>();
-            world.register_res::<iddqd>();
-
-            // Check registries
-            assert!(world.resources.contains_key(idkfa::ID));
-            assert!(world.resources.contains_key(iddqd::ID));
+            let stale = world.spawn().finish();
+            assert!(world.despawn(0));
 
+            // Slot 0 gets reused by a new Entity
+            let fresh = world.spawn().finish();
+            assert!(fresh.id() == stale.id());
 
-            world.deregister_res::<idkfa>();
-            world.deregister_res::<iddqd>();
+            assert!(!world.is_alive(&stale));
+            assert!(world.is_alive(&fresh));
 
-            // Check deregistries
-            assert!(!world.resources.contains_key(idkfa::ID));
-            assert!(!world.resources.contains_key(iddqd::ID));
+            assert!(!world.despawn_with_token(stale));
+            assert!(world.entities.contains_key(&0));
+            assert!(world.despawn_with_token(fresh));
         }
         #[test]
+        #[cfg(debug_assertions)]
         #[should_panic]
-        fn register_res_collision(){
-            struct idkfa;
-            struct iddqd;
-
-            impl Resource for idkfa{
-                const ID: &'static str = "idkfa";
+        fn spawn_duplicate_comp(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
             
-                fn new() -> Self {
-                    Self
-                }
-            }
-            impl Resource for iddqd{
                 const ID: &'static str = "idkfa";
-            
-                fn new() -> Self {
-                    Self
-                }
             }
 
             let mut world = World::new();
-            world.register_res::<idkfa>();
-            world.register_res::<iddqd>();
+            world.register_comp::<idkfa>();
+
+            // SHOULD PANIC
+            world.spawn().with(idkfa(0)).with(idkfa(1)).finish();
         }
         #[test]
-        fn register_event(){
-            struct idkfa;
-            struct iddqd;
-
-            impl Event for idkfa{
+        fn insert_comp(){
+            struct idkfa(u8);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
                 const ID: &'static str = "idkfa";
             }
-            impl Event for iddqd{
-                const ID: &'static str = "iddqd";
-            }
 
             let mut world = World::new();
+            let id = world.spawn().finish().id();
 
-            world.register_event::<idkfa>();
-            world.register_event::<iddqd>();
-            // Check registries
-            assert!(world.events.get_registry().contains(idkfa::ID));
-            assert!(world.events.get_registry().contains(iddqd::ID));
+            world.insert_comp_or_register(id, idkfa(5));
+            assert!(world.has_comp("idkfa"));
+            assert!(world.fetch::<idkfa>().get(&id).unwrap().0 == 5);
 
-            world.deregister_event::<idkfa>();
-            world.deregister_event::<iddqd>();
-            // Check deregistries
-            assert!(!world.events.get_registry().contains(idkfa::ID));
-            assert!(!world.events.get_registry().contains(iddqd::ID));
+            // Already registered, just replaces
+            world.insert_comp_or_register(id, idkfa(10));
+            world.insert_comp(id, idkfa(15));
+            assert!(world.fetch::<idkfa>().get(&id).unwrap().0 == 15);
         }
         #[test]
         #[should_panic]
-        fn register_event_collision(){
+        fn insert_comp_unregistered(){
             struct idkfa;
-            struct iddqd;
-
-            impl Event for idkfa{
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
                 const ID: &'static str = "idkfa";
             }
-            impl Event for iddqd{
+
+            let mut world = World::new();
+            let id = world.spawn().finish().id();
+
+            // SHOULD PANIC
+            world.insert_comp(id, idkfa);
+        }
+        #[test]
+        fn spawn_with_self(){
+            struct idkfa(Token);
+            impl Component for idkfa{
+                type STORAGE = TestStorage<Self>;
+            
                 const ID: &'static str = "idkfa";
             }
 
             let mut world = World::new();
-            world.register_event::<idkfa>();
-            world.register_event::<iddqd>();
-        }
-    }
-    mod test_spawns{
-        use super::*;
+            world.register_comp::<idkfa>();
+
+            world.spawn().finish();
+            let token = world.spawn().with_self(idkfa).finish();
 
+            let mut own = world.fetch::<idkfa>().get(&token.id()).unwrap().0;
+            assert!(own.id() == 1);
+            assert!(own.refresh(&world));
+            assert!(own.matches(&world.get_entities()[&token.id()]));
+        }
         #[test]
-        fn spawn(){
+        fn token_refresh(){
             let mut world = World::new();
 
-            let _ = world.spawn();
+            let mut token = world.spawn().finish();
+            assert!(token.refresh(&world));
+            assert!(token.valid());
 
-            assert!(world.entities.len() == 1);
+            world.despawn(token.id());
+            assert!(!token.refresh(&world));
+
+            // Stays invalid even once the slot is reused
+            world.spawn().finish();
+            assert!(!token.refresh(&world));
+            assert!(!token.valid());
         }
         #[test]
-        fn spawn_build(){
+        fn despawn_hook_own_storage(){
+            struct Remaining(usize);
+            impl Resource for Remaining{
+                const ID: &'static str = "Remaining";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
             struct idkfa;
             impl Component for idkfa{
                 type STORAGE = TestStorage<Self>;
             
                 const ID: &'static str = "idkfa";
+
+                fn on_remove(&mut self, _id: usize, world: &World) {
+                    world.fetch_res_mut::<Remaining>().0 = world.fetch::<idkfa>().len();
+                }
             }
 
             let mut world = World::new();
+            world.register_res::<Remaining>();
             world.register_comp::<idkfa>();
 
+            world.spawn().with(idkfa).finish();
+            world.spawn().with(idkfa).finish();
             world.spawn().with(idkfa).finish();
 
-            assert!(world.entities.len() == 1);
-            assert!(world.fetch::<idkfa>().get(&0).is_some())
+            assert!(world.despawn(0));
+            assert!(world.fetch_res::<Remaining>().0 == 2);
+
+            assert!(world.despawn_many([1, 2]) == 2);
+            assert!(world.fetch_res::<Remaining>().0 == 0);
         }
         #[test]
-        fn despawn_id(){
+        fn despawn_hooks(){
+            struct Counter(u8);
+            impl Resource for Counter{
+                const ID: &'static str = "Counter";
+            
+                fn new() -> Self {
+                    Self(0)
+                }
+            }
+
             struct idkfa;
+            struct iddqd;
             impl Component for idkfa{
                 type STORAGE = TestStorage<Self>;
             
                 const ID: &'static str = "idkfa";
+
+                fn on_remove(&mut self, _id: usize, world: &World) {
+                    world.fetch_res_mut::<Counter>().0 -= 1;
+                }
+            }
+            impl Component for iddqd{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "iddqd";
+
+                fn on_remove(&mut self, _id: usize, world: &World) {
+                    world.fetch_res_mut::<Counter>().0 -= 1;
+                }
             }
 
             let mut world = World::new();
+            world.register_res::<Counter>();
             world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
 
-            let _ = world.spawn().with(idkfa);
+            world.spawn().with(idkfa).with(iddqd).finish();
+            world.fetch_res_mut::<Counter>().0 = 2;
 
             assert!(world.despawn(0));
-            assert!(world.fetch::<idkfa>().get(&0).is_none());
+            assert!(world.fetch_res::<Counter>().0 == 0);
+            assert!(world.get_event_writer::<ComponentRemoved>().current_event_count() == 2);
+            assert!(world.next_free.contains(&0));
+        }
+    }
+    mod test_snapshots{
+        use super::*;
+
+        #[derive(Clone)]
+        struct idkfa(u8);
+        impl Component for idkfa{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "idkfa";
         }
+        #[derive(Clone)]
+        struct iddqd(u8);
+        impl Component for iddqd{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "iddqd";
+        }
+
         #[test]
-        fn despawn_token(){
-            struct idkfa;
-            impl Component for idkfa{
-                type STORAGE = TestStorage<Self>;
-            
-                const ID: &'static str = "idkfa";
+        fn restore(){
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+            world.register_snapshot::<idkfa>();
+
+            let token = world.spawn().with(idkfa(5)).with(iddqd(10)).finish();
+
+            let snapshot = world.snapshot();
+
+            world.fetch_mut::<idkfa>().get_mut(&0).unwrap().0 = 20;
+            world.fetch_mut::<iddqd>().get_mut(&0).unwrap().0 = 20;
+            world.spawn().with(idkfa(1)).with(iddqd(1)).finish();
+
+            world.restore(snapshot);
+
+            assert!(world.entities.len() == 1);
+            assert!(world.is_alive(&token));
+            // Captured Component is reverted
+            assert!(world.fetch::<idkfa>().get(&0).unwrap().0 == 5);
+            assert!(world.fetch::<idkfa>().get(&1).is_none());
+            // Uncaptured one keeps it's value, but loses the Entity that no longer exists
+            assert!(world.fetch::<iddqd>().get(&0).unwrap().0 == 20);
+            assert!(world.fetch::<iddqd>().get(&1).is_none());
+
+            // The freed ID is handed out again
+            assert!(world.spawn().finish().id() == 1);
+        }
+        #[test]
+        fn export_import(){
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+
+            world.spawn().with(idkfa(5)).with(iddqd(5)).finish();
+            world.spawn().with(iddqd(10)).finish();
+            world.spawn().with(idkfa(15)).finish();
+
+            let export = world.export_comp::<idkfa>();
+            assert!(export.iter().map(|(id, comp)| (*id, comp.0)).collect::<Vec<_>>() == [(0, 5), (2, 15)]);
+
+            world.fetch_mut::<idkfa>().get_mut(&0).unwrap().0 = 20;
+            world.fetch_mut::<idkfa>().insert(1, idkfa(20));
+            world.fetch_mut::<idkfa>().remove(&2);
+            world.fetch_mut::<iddqd>().get_mut(&0).unwrap().0 = 20;
+
+            world.import_comp(export);
+
+            assert!(world.export_comp::<idkfa>().iter().map(|(id, comp)| (*id, comp.0)).collect::<Vec<_>>() == [(0, 5), (2, 15)]);
+            // Other Components are left alone
+            assert!(world.fetch::<iddqd>().get(&0).unwrap().0 == 20);
+
+            // Dead Entities are skipped
+            world.despawn(2);
+            world.import_comp(vec![(2, idkfa(1)), (7, idkfa(1))]);
+            assert!(world.fetch::<idkfa>().is_empty());
+        }
+        #[test]
+        fn restore_despawned(){
+            let mut world = World::new();
+            world.register_comp::<idkfa>();
+            world.register_snapshot::<idkfa>();
+
+            let token = world.spawn().with(idkfa(5)).finish();
+            let snapshot = world.snapshot();
+
+            assert!(world.despawn_with_token(token));
+            world.restore(snapshot);
+
+            assert!(world.is_alive(&token));
+            assert!(world.fetch::<idkfa>().get(&0).unwrap().0 == 5);
+        }
+    }
+
+    mod test_scenes{
+        use super::*;
+
+        struct idkfa(u8);
+        impl Component for idkfa{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "idkfa";
+        }
+        impl SerializableComponent for idkfa{
+            fn serialize(&self) -> Vec<u8> {
+                vec![self.0]
+            }
+            fn deserialize(bytes: &[u8]) -> Option<Self> {
+                match bytes{
+                    [val] => Some(Self(*val)),
+                    _ => None
+                }
+            }
+        }
+        struct iddqd(u32);
+        impl Component for iddqd{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "iddqd";
+        }
+        impl SerializableComponent for iddqd{
+            fn serialize(&self) -> Vec<u8> {
+                self.0.to_le_bytes().to_vec()
             }
+            fn deserialize(bytes: &[u8]) -> Option<Self> {
+                Some(Self(u32::from_le_bytes(bytes.try_into().ok()?)))
+            }
+        }
+        struct idclip;
+        impl Component for idclip{
+            type STORAGE = TestStorage<Self>;
+        
+            const ID: &'static str = "idclip";
+        }
 
+        fn setup() -> World{
             let mut world = World::new();
             world.register_comp::<idkfa>();
+            world.register_comp::<iddqd>();
+            world.register_comp::<idclip>();
+            world.register_serializable::<idkfa>();
+            world.register_serializable::<iddqd>();
+            world
+        }
 
-            let token = world.spawn().with(idkfa).get_token();
+        #[test]
+        fn round_trip(){
+            let mut world = setup();
+
+            world.spawn().with(idkfa(1)).with(iddqd(100_000)).finish();
+            world.spawn().finish();
+            world.spawn().with(iddqd(7)).with(idclip).finish();
+            world.spawn().with(idkfa(3)).finish();
+            // Leave a gap so IDs have to be kept
+            world.despawn(1);
+
+            let scene = world.serialize_scene();
+            assert!(scene.entities.len() == 3);
+
+            let mut restored = setup();
+            restored.deserialize_scene(&scene);
+
+            assert!(restored.entities.keys().copied().collect::<Vec<_>>() == [0, 2, 3]);
+            assert!(restored.fetch::<idkfa>().get(&0).unwrap().0 == 1);
+            assert!(restored.fetch::<iddqd>().get(&0).unwrap().0 == 100_000);
+            assert!(restored.fetch::<iddqd>().get(&2).unwrap().0 == 7);
+            assert!(restored.fetch::<idkfa>().get(&3).unwrap().0 == 3);
+            // Not serializable, not saved
+            assert!(restored.fetch::<idclip>().is_empty());
+
+            assert!(restored.serialize_scene() == scene);
+            // The gap is reused
+            assert!(restored.spawn().finish().id() == 1);
+        }
+        #[test]
+        fn round_trip_tokens(){
+            struct Link(Token);
+            impl Component for Link{
+                type STORAGE = TestStorage<Self>;
+            
+                const ID: &'static str = "Link";
+            }
+            impl SerializableComponent for Link{
+                fn serialize(&self) -> Vec<u8> {
+                    let mut bytes = (self.0.id() as u64).to_le_bytes().to_vec();
+                    bytes.extend(self.0.hash().to_le_bytes());
+                    bytes
+                }
+                fn deserialize(bytes: &[u8]) -> Option<Self> {
+                    let id = u64::from_le_bytes(bytes.get(..8)?.try_into().ok()?);
+                    let hash = u32::from_le_bytes(bytes.get(8..)?.try_into().ok()?);
+                    Some(Self(Token::from_parts(id as usize, hash)))
+                }
+            }
 
-            assert!(world.despawn_with_token(token.clone()));
-            assert!(world.fetch::<idkfa>().get_from_token(&token.clone()).is_none());
+            let mut world = setup();
+            world.register_comp::<Link>();
+            world.register_serializable::<Link>();
+
+            let parent = world.spawn().finish();
+            let child = world.spawn().with(Link(parent)).finish();
+
+            let scene = world.serialize_scene();
+
+            let mut restored = setup();
+            restored.register_comp::<Link>();
+            restored.register_serializable::<Link>();
+            restored.deserialize_scene(&scene);
+
+            // Tokens from before the save, as well as the ones saved inside Components
+            assert!(restored.is_alive(&parent));
+            assert!(restored.is_alive(&child));
+            let mut link = restored.fetch::<Link>().get(&child.id()).unwrap().0;
+            assert!(link.refresh(&restored));
+            assert!(link.id() == parent.id());
+        }
+        #[test]
+        #[should_panic]
+        fn invalid_bytes(){
+            let mut scene = Scene::default();
+            scene.entities.push(SceneEntity{
+                id: 0,
+                hash: 0,
+                components: BTreeMap::from([(String::from("idkfa"), vec![1, 2, 3])])
+            });
+
+            // SHOULD PANIC
+            setup().deserialize_scene(&scene);
         }
     }
+
     #[test]
     fn test_meta(){
         struct idkfa;
@@ -611,4 +2472,21 @@ mod tests{
             assert!(triggers.trigger_count() == 1)
         }
     }
+    #[test]
+    fn test_trigger_payload(){
+        let mut world = World::new();
+
+        world.get_trigger_writer().send_with("idkfa", "payload");
+        // Not readable until the Trigger fires
+        assert!(world.take_trigger_payload::<&str>("idkfa").is_none());
+
+        world.take_triggers();
+        assert!(world.take_trigger_payload::<&str>("idkfa") == Some("payload"));
+
+        // Gone after the next batch of Triggers
+        world.get_trigger_writer().send_with("idkfa", "payload");
+        world.take_triggers();
+        world.take_triggers();
+        assert!(world.take_trigger_payload::<&str>("idkfa").is_none());
+    }
 }
\ No newline at end of file
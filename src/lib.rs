@@ -1,4 +1,5 @@
 #![allow(nonstandard_style)]
+#![cfg_attr(test, allow(clippy::len_zero, clippy::clone_on_copy))]
 //! # Manufacture Engine
 //! A bare-bones ECS-based engine responsible for powering my projects
 //! 